@@ -0,0 +1,95 @@
+use std::str::FromStr;
+
+// One line of answers.csv: the answer we submitted for a day and part, along
+// with a hash of the input it was computed from.
+#[derive(PartialEq, Debug)]
+pub struct RecordedAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub answer: String,
+}
+
+impl FromStr for RecordedAnswer {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<RecordedAnswer, String> {
+        let fields: Vec<&str> = line.split(',').map(|field| field.trim()).collect();
+
+        let [day, part, input_hash, answer] = fields[..] else {
+            return Err(format!(
+                "expected day,part,input_hash,answer but got \"{line}\""
+            ));
+        };
+
+        Ok(RecordedAnswer {
+            day: day.parse().map_err(|_| format!("invalid day \"{day}\""))?,
+            part: part
+                .parse()
+                .map_err(|_| format!("invalid part \"{part}\""))?,
+            input_hash: input_hash.to_string(),
+            answer: answer.to_string(),
+        })
+    }
+}
+
+pub const MANIFEST: &str = include_str!("../answers.csv");
+
+// Blank lines and lines starting with # are ignored
+pub fn parse_manifest(manifest: &str) -> Result<Vec<RecordedAnswer>, String> {
+    manifest
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(RecordedAnswer::from_str)
+        .collect()
+}
+
+// 64 bit FNV-1a, which is plenty to notice that an input file has been
+// swapped or edited, and stays the same across Rust versions unlike
+// std's DefaultHasher.
+pub fn input_hash(input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+
+    for byte in input.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_line() {
+        let answer: RecordedAnswer = "1, 2, 0123456789abcdef, 55614".parse().unwrap();
+        assert_eq!(
+            RecordedAnswer {
+                day: 1,
+                part: 2,
+                input_hash: "0123456789abcdef".to_string(),
+                answer: "55614".to_string(),
+            },
+            answer
+        );
+    }
+
+    #[test]
+    fn parse_line_with_missing_field() {
+        assert!("1,2,55614".parse::<RecordedAnswer>().is_err());
+    }
+
+    #[test]
+    fn manifest_skips_comments_and_blank_lines() {
+        let manifest = "# day,part,input_hash,answer\n\n1,1,abc,1\n2,1,def,2\n";
+        assert_eq!(2, parse_manifest(manifest).unwrap().len());
+    }
+
+    #[test]
+    fn known_hashes() {
+        assert_eq!("cbf29ce484222325", input_hash(""));
+        assert_eq!("af63dc4c8601ec8c", input_hash("a"));
+    }
+}
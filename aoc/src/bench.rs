@@ -0,0 +1,158 @@
+use aoc_common::error::ParseError;
+use aoc_common::solution::Solution;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// Parse and solve timings for one day and part, measured separately so a slow
+// parser doesn't hide in a fast solution or vice versa
+#[derive(Debug)]
+pub struct Benchmark {
+    pub day: u8,
+    pub part: u8,
+    pub iterations: usize,
+    pub parse: Summary,
+    pub solve: Summary,
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Summary {
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Summary {
+    pub fn from(samples: &[Duration]) -> Summary {
+        assert!(!samples.is_empty(), "Need at least one sample to summarise");
+
+        let nanos: Vec<f64> = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        Summary {
+            mean: Duration::from_nanos(mean.round() as u64),
+            median,
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+// Parses and solves `part` of `input` `iterations` times
+pub fn measure<S: Solution>(
+    part: u8,
+    input: &str,
+    iterations: usize,
+) -> Result<Benchmark, ParseError> {
+    let mut parse_times = vec![];
+    let mut solve_times = vec![];
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let puzzle = S::parse(black_box(input))?;
+        parse_times.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::solve_parsed(part, &puzzle)?);
+        solve_times.push(start.elapsed());
+    }
+
+    Ok(Benchmark {
+        day: S::DAY,
+        part,
+        iterations,
+        parse: Summary::from(&parse_times),
+        solve: Summary::from(&solve_times),
+    })
+}
+
+// Times are in nanoseconds so results can be compared between commits
+// without worrying about units
+pub fn to_json(benchmarks: &[Benchmark]) -> String {
+    let entries: Vec<String> = benchmarks
+        .iter()
+        .map(|benchmark| {
+            format!(
+                "  {{\"day\": {}, \"part\": {}, \"iterations\": {}, \"parse\": {}, \"solve\": {}}}",
+                benchmark.day,
+                benchmark.part,
+                benchmark.iterations,
+                summary_json(&benchmark.parse),
+                summary_json(&benchmark.solve)
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]", entries.join(",\n"))
+}
+
+fn summary_json(summary: &Summary) -> String {
+    format!(
+        "{{\"mean_ns\": {}, \"median_ns\": {}, \"stddev_ns\": {}}}",
+        summary.mean.as_nanos(),
+        summary.median.as_nanos(),
+        summary.stddev.as_nanos()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples
+            .iter()
+            .map(|ms| Duration::from_millis(*ms))
+            .collect()
+    }
+
+    #[test]
+    fn summary_of_odd_number_of_samples() {
+        let summary = Summary::from(&millis(&[2, 4, 4, 4, 5, 5, 7, 9, 1]));
+        assert_eq!(Duration::from_nanos(4_555_556), summary.mean);
+        assert_eq!(Duration::from_millis(4), summary.median);
+    }
+
+    #[test]
+    fn summary_of_even_number_of_samples() {
+        let summary = Summary::from(&millis(&[2, 4, 4, 4, 5, 5, 7, 9]));
+        assert_eq!(
+            Summary {
+                mean: Duration::from_millis(5),
+                median: Duration::from_nanos(4_500_000),
+                stddev: Duration::from_millis(2),
+            },
+            summary
+        );
+    }
+
+    #[test]
+    fn json_output() {
+        let summary = || Summary::from(&millis(&[1]));
+        let benchmarks = [Benchmark {
+            day: 3,
+            part: 2,
+            iterations: 1,
+            parse: summary(),
+            solve: summary(),
+        }];
+
+        assert_eq!(
+            "[\n  {\"day\": 3, \"part\": 2, \"iterations\": 1, \
+             \"parse\": {\"mean_ns\": 1000000, \"median_ns\": 1000000, \"stddev_ns\": 0}, \
+             \"solve\": {\"mean_ns\": 1000000, \"median_ns\": 1000000, \"stddev_ns\": 0}}\n]",
+            to_json(&benchmarks)
+        );
+    }
+}
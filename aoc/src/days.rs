@@ -0,0 +1,54 @@
+use crate::bench::{self, Benchmark};
+use aoc_common::error::ParseError;
+use aoc_common::solution::Solution;
+use day_01::Day01;
+use day_02::Day02;
+use day_03::Day03;
+use day_04::Day04;
+use day_05::Day05;
+use day_06::Day06;
+use day_07::Day07;
+use day_08::Day08;
+use day_09::Day09;
+
+pub struct Day {
+    pub number: u8,
+    pub input: &'static str,
+    solve: fn(u8, &str) -> Result<String, ParseError>,
+    bench: fn(u8, &str, usize) -> Result<Benchmark, ParseError>,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Day {
+        Day {
+            number: S::DAY,
+            input: S::INPUT,
+            solve: |part, input| S::solve(part, input).map(|answer| answer.to_string()),
+            bench: bench::measure::<S>,
+        }
+    }
+
+    pub fn solve(&self, part: u8, input: &str) -> Result<String, ParseError> {
+        (self.solve)(part, input)
+    }
+
+    pub fn bench(&self, part: u8, input: &str, iterations: usize) -> Result<Benchmark, ParseError> {
+        (self.bench)(part, input, iterations)
+    }
+}
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+pub static DAYS: [Day; 9] = [
+    Day::of::<Day01>(),
+    Day::of::<Day02>(),
+    Day::of::<Day03>(),
+    Day::of::<Day04>(),
+    Day::of::<Day05>(),
+    Day::of::<Day06>(),
+    Day::of::<Day07>(),
+    Day::of::<Day08>(),
+    Day::of::<Day09>(),
+];
@@ -0,0 +1,3 @@
+pub mod answers;
+pub mod bench;
+pub mod days;
@@ -0,0 +1,212 @@
+use aoc::bench::{self, Benchmark};
+use aoc::days::{self, Day, DAYS};
+use aoc_common::diagnostic;
+use aoc_common::error::ParseError;
+use aoc_common::input::InputSource;
+use clap::{Args, Parser, Subcommand};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+#[derive(Parser)]
+#[command(about = "Run Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day (optionally a single part), or every solved puzzle with --all
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+    },
+    /// Time parsing and solving separately over many iterations
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// How many times to parse and solve each part
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+
+        /// Print the results as JSON, e.g. to save and compare between commits
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Day to run
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Run only this part (both parts are run when omitted)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2), requires = "day")]
+    part: Option<u8>,
+
+    /// Puzzle input file to use instead of the one bundled with the day (`-` reads stdin)
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+
+    /// Run every solved puzzle against its bundled input
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+}
+
+// A day and part to run, along with the input to run it on
+struct Task {
+    day: &'static Day,
+    part: u8,
+    input: String,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { selection } if selection.all => print_results_table(),
+        Command::Run { selection } => {
+            let tasks = match tasks(&selection) {
+                Ok(tasks) => tasks,
+                Err(exit_code) => return exit_code,
+            };
+
+            for task in tasks {
+                match run(task.day, task.part, &task.input) {
+                    (Ok(answer), _) => {
+                        println!("Day {} part {}: {answer}", task.day.number, task.part)
+                    }
+                    (Err(err), _) => return report_invalid_input(&task, &err),
+                }
+            }
+        }
+        Command::Bench {
+            selection,
+            iterations,
+            json,
+        } => {
+            let tasks = match tasks(&selection) {
+                Ok(tasks) => tasks,
+                Err(exit_code) => return exit_code,
+            };
+
+            let mut benchmarks = vec![];
+            for task in tasks {
+                match task.day.bench(task.part, &task.input, iterations as usize) {
+                    Ok(benchmark) => benchmarks.push(benchmark),
+                    Err(err) => return report_invalid_input(&task, &err),
+                }
+            }
+
+            if json {
+                println!("{}", bench::to_json(&benchmarks));
+            } else {
+                print_benchmark_table(&benchmarks);
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+// Works out what to run from the command line, reporting why if it can't
+fn tasks(selection: &Selection) -> Result<Vec<Task>, ExitCode> {
+    if selection.all {
+        return Ok(DAYS
+            .iter()
+            .flat_map(|day| {
+                (1..=2).map(|part| Task {
+                    day,
+                    part,
+                    input: day.input.to_string(),
+                })
+            })
+            .collect());
+    }
+
+    let number = selection.day.expect("clap requires --day without --all");
+    let Some(day) = days::find(number) else {
+        eprintln!("Day {number} has not been solved yet");
+        return Err(ExitCode::FAILURE);
+    };
+
+    let source = InputSource::from_arg(selection.input.as_deref());
+    let input = match source.read(day.input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Could not read {source}: {err}");
+            return Err(ExitCode::FAILURE);
+        }
+    };
+
+    let parts = match selection.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    Ok(parts
+        .into_iter()
+        .map(|part| Task {
+            day,
+            part,
+            input: input.clone(),
+        })
+        .collect())
+}
+
+fn report_invalid_input(task: &Task, err: &ParseError) -> ExitCode {
+    eprintln!("Invalid input for day {}", task.day.number);
+    eprint!("{}", diagnostic::render(&task.input, err));
+    ExitCode::FAILURE
+}
+
+fn run(day: &Day, part: u8, input: &str) -> (Result<String, ParseError>, Duration) {
+    let start = Instant::now();
+    let answer = day.solve(part, input);
+
+    (answer, start.elapsed())
+}
+
+fn print_results_table() {
+    println!(
+        "{:>3}  {:>4}  {:>16}  {:>12}",
+        "Day", "Part", "Answer", "Time"
+    );
+
+    for day in DAYS.iter() {
+        for part in 1..=2 {
+            let (answer, duration) = run(day, part, day.input);
+            let answer = answer.unwrap_or_else(|err| format!("invalid input ({err})"));
+            println!(
+                "{:>3}  {:>4}  {:>16}  {:>12}",
+                day.number,
+                part,
+                answer,
+                format!("{duration:.2?}")
+            );
+        }
+    }
+}
+
+fn print_benchmark_table(benchmarks: &[Benchmark]) {
+    println!(
+        "{:>3}  {:>4}  {:>12}  {:>12}  {:>12}  {:>12}  {:>12}  {:>12}",
+        "Day", "Part", "Parse mean", "median", "stddev", "Solve mean", "median", "stddev"
+    );
+
+    for benchmark in benchmarks {
+        println!(
+            "{:>3}  {:>4}  {:>12}  {:>12}  {:>12}  {:>12}  {:>12}  {:>12}",
+            benchmark.day,
+            benchmark.part,
+            format!("{:.2?}", benchmark.parse.mean),
+            format!("{:.2?}", benchmark.parse.median),
+            format!("{:.2?}", benchmark.parse.stddev),
+            format!("{:.2?}", benchmark.solve.mean),
+            format!("{:.2?}", benchmark.solve.median),
+            format!("{:.2?}", benchmark.solve.stddev),
+        );
+    }
+}
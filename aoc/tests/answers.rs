@@ -0,0 +1,77 @@
+use aoc::answers::{self, RecordedAnswer};
+use aoc::days::{self, DAYS};
+
+fn recorded_answers() -> Vec<RecordedAnswer> {
+    answers::parse_manifest(answers::MANIFEST).expect("answers.csv should be well formed")
+}
+
+fn check(day_number: u8, part: u8) {
+    let recorded = recorded_answers()
+        .into_iter()
+        .find(|recorded| recorded.day == day_number && recorded.part == part)
+        .unwrap_or_else(|| panic!("No answer recorded for day {day_number} part {part}"));
+    let day = days::find(day_number).unwrap();
+
+    assert_eq!(
+        recorded.input_hash,
+        answers::input_hash(day.input),
+        "The bundled input for day {day_number} has changed since its answers were recorded"
+    );
+    assert_eq!(
+        recorded.answer,
+        day.solve(part, day.input)
+            .unwrap_or_else(|err| panic!("Could not parse the input for day {day_number}: {err}")),
+        "Day {day_number} part {part} no longer produces the recorded answer"
+    );
+}
+
+macro_rules! answer_tests {
+    ($($(#[$attr:meta])* $name:ident: $day:expr, $part:expr;)*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                check($day, $part);
+            }
+        )*
+    };
+}
+
+answer_tests! {
+    day_01_part_1: 1, 1;
+    day_01_part_2: 1, 2;
+    day_02_part_1: 2, 1;
+    day_02_part_2: 2, 2;
+    day_03_part_1: 3, 1;
+    day_03_part_2: 3, 2;
+    day_04_part_1: 4, 1;
+    day_04_part_2: 4, 2;
+    day_05_part_1: 5, 1;
+    #[ignore = "brute forces ~2 billion seeds, run with `cargo test --release -- --ignored`"]
+    day_05_part_2: 5, 2;
+    day_06_part_1: 6, 1;
+    day_06_part_2: 6, 2;
+    day_07_part_1: 7, 1;
+    day_07_part_2: 7, 2;
+    day_08_part_1: 8, 1;
+    day_08_part_2: 8, 2;
+    day_09_part_1: 9, 1;
+    day_09_part_2: 9, 2;
+}
+
+#[test]
+fn every_solved_part_has_a_recorded_answer() {
+    let recorded = recorded_answers();
+
+    for day in DAYS.iter() {
+        for part in 1..=2 {
+            assert!(
+                recorded
+                    .iter()
+                    .any(|answer| answer.day == day.number && answer.part == part),
+                "Record an answer for day {} part {part} in answers.csv",
+                day.number
+            );
+        }
+    }
+}
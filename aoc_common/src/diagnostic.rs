@@ -0,0 +1,96 @@
+use crate::error::ParseError;
+
+// Renders a parse error against the input it came from, rustc style, so it's
+// obvious which part of a hand-edited or truncated input needs fixing:
+//
+//   error: expected a cube colour (red, green or blue), found "yellow"
+//    --> line 1, column 11
+//     |
+//   1 | Game 1: 3 yellow, 4 red
+//     |           ^^^^^^
+pub fn render(input: &str, error: &ParseError) -> String {
+    let mut rendered = format!(
+        "error: {}\n --> line {}, column {}\n",
+        error.explanation(),
+        error.line,
+        error.column
+    );
+
+    // Errors about something missing from the end of the input can point past
+    // the last line, in which case there's nothing to underline
+    let Some(line) = input.lines().nth(error.line.saturating_sub(1)) else {
+        return rendered;
+    };
+
+    let gutter = " ".repeat(error.line.to_string().len());
+    let line_length = line.chars().count();
+    let start = (error.column - 1).min(line_length);
+    // Always at least one caret, even when pointing at something missing
+    let width = error.text.chars().count().min(line_length - start).max(1);
+
+    rendered.push_str(&format!("{gutter} |\n"));
+    rendered.push_str(&format!("{} | {line}\n", error.line));
+    rendered.push_str(&format!(
+        "{gutter} | {}{}\n",
+        " ".repeat(start),
+        "^".repeat(width)
+    ));
+
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ParseErrorKind;
+
+    #[test]
+    fn underlines_offending_text() {
+        let input = "Game 1: 3 blue\nGame 2: 3 yellow, 4 red\n";
+        let line = input.lines().nth(1).unwrap();
+        let error = ParseError::expected("a colour", line, &line[10..16]).on_line(2);
+
+        assert_eq!(
+            "error: expected a colour, found \"yellow\"\n \
+             --> line 2, column 11\n  \
+             |\n\
+             2 | Game 2: 3 yellow, 4 red\n  \
+             |           ^^^^^^\n",
+            render(input, &error)
+        );
+    }
+
+    #[test]
+    fn single_caret_for_missing_text() {
+        let input = "Game 1: 3";
+        let error = ParseError::expected("a colour", input, &input[input.len()..]);
+
+        assert!(render(input, &error).ends_with("1 | Game 1: 3\n  |          ^\n"));
+    }
+
+    #[test]
+    fn gutter_fits_line_number() {
+        let input = "1\n".repeat(11) + "x";
+        let error = ParseError::expected("a number", "x", "x").on_line(12);
+
+        assert!(render(&input, &error).ends_with("   |\n12 | x\n   | ^\n"));
+    }
+
+    #[test]
+    fn line_past_end_of_input() {
+        let error = ParseError::expected("a blank line", "", "").on_line(2);
+
+        assert_eq!(
+            "error: expected a blank line, found nothing\n --> line 2, column 1\n",
+            render("LR", &error)
+        );
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        let input = "ñ ñ 12x";
+        let error = ParseError::new(ParseErrorKind::InvalidNumber, input, &input[6..]);
+
+        assert!(render(input, &error).ends_with("1 | ñ ñ 12x\n  |     ^^^\n"));
+    }
+}
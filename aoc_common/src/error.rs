@@ -0,0 +1,157 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum ParseErrorKind {
+    NoMatch { pattern: String },
+    InvalidNumber,
+    // Something specific to the puzzle, e.g. "a cube colour (red, green or blue)"
+    Expected(String),
+}
+
+// Where in the input parsing failed and what was found there. Lines and
+// columns count from 1, and columns are in characters rather than bytes.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+}
+
+impl ParseError {
+    // `offending` should be a slice of `text`, the line being parsed, so the
+    // error can point at it. Otherwise the error points at the start of the line.
+    pub fn new(kind: ParseErrorKind, text: &str, offending: &str) -> ParseError {
+        let column = match offset_of(text, offending) {
+            Some(offset) => text[..offset].chars().count() + 1,
+            None => 1,
+        };
+
+        ParseError {
+            kind,
+            line: 1,
+            column,
+            text: offending.to_string(),
+        }
+    }
+
+    pub fn expected(expected: &str, text: &str, offending: &str) -> ParseError {
+        ParseError::new(
+            ParseErrorKind::Expected(expected.to_string()),
+            text,
+            offending,
+        )
+    }
+
+    // For an error from parsing `inner`, a slice of `outer`, makes the column
+    // relative to `outer` instead
+    pub fn within(mut self, outer: &str, inner: &str) -> ParseError {
+        if let Some(offset) = offset_of(outer, inner) {
+            self.column += outer[..offset].chars().count();
+        }
+        self
+    }
+
+    // For an error from parsing a single line, records which line of the
+    // input that was
+    pub fn on_line(mut self, line: usize) -> ParseError {
+        self.line = line;
+        self
+    }
+}
+
+// Byte offset of `inner` within `outer`, if `inner` is a slice of `outer`
+fn offset_of(outer: &str, inner: &str) -> Option<usize> {
+    let offset = (inner.as_ptr() as usize).checked_sub(outer.as_ptr() as usize)?;
+
+    if offset + inner.len() <= outer.len() {
+        Some(offset)
+    } else {
+        None
+    }
+}
+
+impl ParseError {
+    // What went wrong, without saying where
+    pub fn explanation(&self) -> String {
+        match &self.kind {
+            ParseErrorKind::NoMatch { pattern } => {
+                format!("\"{}\" does not match the pattern {pattern}", self.text)
+            }
+            ParseErrorKind::InvalidNumber => format!("\"{}\" is not a valid number", self.text),
+            ParseErrorKind::Expected(expected) if self.text.is_empty() => {
+                format!("expected {expected}, found nothing")
+            }
+            ParseErrorKind::Expected(expected) => {
+                format!("expected {expected}, found \"{}\"", self.text)
+            }
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.column,
+            self.explanation()
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_of_slice() {
+        let line = "Game 1: 3 yellow";
+        let error = ParseError::new(ParseErrorKind::InvalidNumber, line, &line[10..]);
+        assert_eq!(11, error.column);
+        assert_eq!("yellow", error.text);
+    }
+
+    #[test]
+    fn column_counts_characters() {
+        let line = "ñ 12x";
+        let error = ParseError::new(ParseErrorKind::InvalidNumber, line, &line[3..]);
+        assert_eq!(3, error.column);
+    }
+
+    #[test]
+    fn column_of_unrelated_text() {
+        let error = ParseError::new(ParseErrorKind::InvalidNumber, "1 2 3", "x");
+        assert_eq!(1, error.column);
+    }
+
+    #[test]
+    fn within_outer_text() {
+        let line = "Game 1: 3 blue; 4 yellow";
+        let draw = &line[16..];
+        let error = ParseError::expected("a colour", draw, &draw[2..])
+            .within(line, draw)
+            .on_line(4);
+
+        assert_eq!(4, error.line);
+        assert_eq!(19, error.column);
+        assert_eq!(
+            "line 4, column 19: expected a colour, found \"yellow\"",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn display_missing_text() {
+        let line = "Game 1: 3";
+        let error = ParseError::expected("a colour", line, &line[line.len()..]);
+        assert_eq!(
+            "line 1, column 10: expected a colour, found nothing",
+            error.to_string()
+        );
+    }
+}
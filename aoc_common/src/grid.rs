@@ -0,0 +1,415 @@
+use crate::error::ParseError;
+use std::collections::HashSet;
+use std::ops::{Index, IndexMut};
+
+// A cell's position. (0, 0) is the top left, x goes right and y goes down.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+// Which cells count as next to each other
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Adjacency {
+    // Up, down, left and right
+    Four,
+    // Diagonals too
+    Eight,
+}
+
+// Offsets to the neighbours, in reading order
+const FOUR: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const EIGHT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl Adjacency {
+    fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Adjacency::Four => &FOUR,
+            Adjacency::Eight => &EIGHT,
+        }
+    }
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Point {
+        Point { x, y }
+    }
+
+    // Neighbours in reading order, leaving out any that would be off the top
+    // or left edge. Use Grid::neighbors to leave out the other edges too.
+    pub fn neighbors(self, adjacency: Adjacency) -> impl Iterator<Item = Point> {
+        adjacency.offsets().iter().filter_map(move |(dx, dy)| {
+            Some(Point::new(
+                self.x.checked_add_signed(*dx)?,
+                self.y.checked_add_signed(*dy)?,
+            ))
+        })
+    }
+
+    pub fn is_neighbor(self, other: Point, adjacency: Adjacency) -> bool {
+        let (dx, dy) = (self.x.abs_diff(other.x), self.y.abs_diff(other.y));
+        match adjacency {
+            Adjacency::Four => dx + dy == 1,
+            Adjacency::Eight => self != other && dx <= 1 && dy <= 1,
+        }
+    }
+}
+
+// A rectangle of cells, stored a row at a time
+#[derive(PartialEq, Debug, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // `cells` are in reading order, and there must be width * height of them
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "a {width}x{height} grid needs {} cells",
+            width * height
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // One row per line and one cell per character. Every line has to be the
+    // same length.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (i, line) in input.lines().enumerate() {
+            let length = line.chars().count();
+            let width = *width.get_or_insert(length);
+            if length != width {
+                let expected = format!("a line {width} characters long, like the first");
+                return Err(ParseError::expected(&expected, line, line).on_line(i + 1));
+            }
+
+            cells.extend(line.chars().map(&mut cell));
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            Some(&self.cells[point.y * self.width + point.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.y * self.width + point.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        if x < self.width {
+            Some(self.cells.iter().skip(x).step_by(self.width))
+        } else {
+            None
+        }
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    // Every position in reading order
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    // Neighbours of `point` that are inside the grid, in reading order
+    pub fn neighbors(&self, point: Point, adjacency: Adjacency) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width, self.height);
+        point
+            .neighbors(adjacency)
+            .filter(move |neighbor| neighbor.x < width && neighbor.y < height)
+    }
+
+    pub fn neighbors_4(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbors(point, Adjacency::Four)
+    }
+
+    pub fn neighbors_8(&self, point: Point) -> impl Iterator<Item = Point> {
+        self.neighbors(point, Adjacency::Eight)
+    }
+
+    // The cells connected to `start` through cells that are `included`, in
+    // reading order. Empty if `start` itself isn't included.
+    pub fn region(
+        &self,
+        start: Point,
+        adjacency: Adjacency,
+        included: impl Fn(&T) -> bool,
+    ) -> Vec<Point> {
+        let mut seen = HashSet::new();
+        self.flood(start, adjacency, &included, &mut seen)
+    }
+
+    // Every separate region of `included` cells, ordered by where they start
+    pub fn regions(&self, adjacency: Adjacency, included: impl Fn(&T) -> bool) -> Vec<Vec<Point>> {
+        let mut seen = HashSet::new();
+        let mut regions = vec![];
+
+        for point in self.points() {
+            if !seen.contains(&point) && included(&self[point]) {
+                regions.push(self.flood(point, adjacency, &included, &mut seen));
+            }
+        }
+
+        regions
+    }
+
+    fn flood(
+        &self,
+        start: Point,
+        adjacency: Adjacency,
+        included: &impl Fn(&T) -> bool,
+        seen: &mut HashSet<Point>,
+    ) -> Vec<Point> {
+        let mut region = vec![];
+        let mut to_visit = vec![start];
+
+        while let Some(point) = to_visit.pop() {
+            if !self.get(point).is_some_and(included) || !seen.insert(point) {
+                continue;
+            }
+            region.push(point);
+            to_visit.extend(self.neighbors(point, adjacency));
+        }
+
+        region.sort_by_key(|point| (point.y, point.x));
+        region
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid::new(width, height, vec![value; width * height])
+    }
+}
+
+// Panics if `point` is outside the grid, like indexing a Vec. Use `get` to
+// check first.
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside a {width}x{height} grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters() -> Grid<char> {
+        Grid::parse("abc\ndef", |c| c).unwrap()
+    }
+
+    fn points(points: &[(usize, usize)]) -> Vec<Point> {
+        points.iter().map(|(x, y)| Point::new(*x, *y)).collect()
+    }
+
+    #[test]
+    fn parse() {
+        let grid = letters();
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!('f', grid[Point::new(2, 1)]);
+    }
+
+    #[test]
+    fn parse_ragged_lines() {
+        let error = Grid::parse("abc\nde\nfgh", |c| c).unwrap_err();
+        assert_eq!(2, error.line);
+        assert_eq!(
+            "line 2, column 1: expected a line 3 characters long, like the first, found \"de\"",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn parse_nothing() {
+        let grid = Grid::parse("", |c| c).unwrap();
+        assert_eq!((0, 0), (grid.width(), grid.height()));
+        assert_eq!(0, grid.points().count());
+    }
+
+    #[test]
+    fn bounds_checked() {
+        let mut grid = letters();
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+        assert_eq!(None, grid.get(Point::new(0, 2)));
+
+        *grid.get_mut(Point::new(1, 1)).unwrap() = 'x';
+        grid[Point::new(0, 0)] = 'y';
+        assert_eq!(Some(&['y', 'b', 'c'][..]), grid.row(0));
+        assert_eq!(Some(&['d', 'x', 'f'][..]), grid.row(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "outside a 3x2 grid")]
+    fn index_outside() {
+        let _ = letters()[Point::new(0, 2)];
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = letters();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+
+        assert_eq!(vec!["abc", "def"], rows);
+        assert_eq!(vec!["ad", "be", "cf"], columns);
+        assert_eq!("cf", grid.column(2).unwrap().collect::<String>());
+        assert!(grid.column(3).is_none());
+        assert_eq!(None, grid.row(2));
+    }
+
+    #[test]
+    fn iter_in_reading_order() {
+        let cells: Vec<(Point, char)> = letters().iter().map(|(p, c)| (p, *c)).collect();
+        assert_eq!((Point::new(0, 0), 'a'), cells[0]);
+        assert_eq!((Point::new(0, 1), 'd'), cells[3]);
+    }
+
+    #[test]
+    fn neighbors_in_the_middle() {
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(
+            points(&[(1, 0), (0, 1), (2, 1), (1, 2)]),
+            grid.neighbors_4(Point::new(1, 1)).collect::<Vec<_>>()
+        );
+        assert_eq!(8, grid.neighbors_8(Point::new(1, 1)).count());
+    }
+
+    #[test]
+    fn neighbors_at_the_edges() {
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(
+            points(&[(1, 0), (0, 1), (1, 1)]),
+            grid.neighbors_8(Point::new(0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            points(&[(2, 1), (1, 2)]),
+            grid.neighbors_4(Point::new(2, 2)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn is_neighbor() {
+        let point = Point::new(1, 1);
+        assert!(point.is_neighbor(Point::new(2, 2), Adjacency::Eight));
+        assert!(!point.is_neighbor(Point::new(2, 2), Adjacency::Four));
+        assert!(point.is_neighbor(Point::new(1, 0), Adjacency::Four));
+        assert!(!point.is_neighbor(point, Adjacency::Eight));
+        assert!(!point.is_neighbor(Point::new(3, 1), Adjacency::Eight));
+    }
+
+    #[test]
+    fn region() {
+        let grid = Grid::parse("##.\n.#.\n..#", |c| c == '#').unwrap();
+
+        assert_eq!(
+            points(&[(0, 0), (1, 0), (1, 1)]),
+            grid.region(Point::new(1, 1), Adjacency::Four, |cell| *cell)
+        );
+        assert_eq!(
+            points(&[(0, 0), (1, 0), (1, 1), (2, 2)]),
+            grid.region(Point::new(0, 0), Adjacency::Eight, |cell| *cell)
+        );
+        assert!(grid
+            .region(Point::new(2, 0), Adjacency::Four, |cell| *cell)
+            .is_empty());
+        assert!(grid
+            .region(Point::new(5, 5), Adjacency::Four, |cell| *cell)
+            .is_empty());
+    }
+
+    #[test]
+    fn regions() {
+        let grid = Grid::parse("##.\n.#.\n..#", |c| c == '#').unwrap();
+
+        assert_eq!(
+            vec![points(&[(0, 0), (1, 0), (1, 1)]), points(&[(2, 2)])],
+            grid.regions(Adjacency::Four, |cell| *cell)
+        );
+        assert_eq!(1, grid.regions(Adjacency::Eight, |cell| *cell).len());
+        assert_eq!(2, grid.regions(Adjacency::Four, |cell| !*cell).len());
+    }
+
+    #[test]
+    fn map() {
+        let grid = letters().map(|c| c.is_ascii_uppercase());
+        assert_eq!(Grid::filled(3, 2, false), grid);
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt::{Display, Formatter};
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::{env, fs, process};
+
+#[derive(PartialEq, Debug)]
+pub enum InputSource {
+    Bundled,
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    // `-` means stdin, anything else is a path. No argument at all falls back
+    // to the input bundled into the binary.
+    pub fn from_arg(arg: Option<&str>) -> InputSource {
+        match arg {
+            None => InputSource::Bundled,
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self, bundled: &str) -> io::Result<String> {
+        match self {
+            InputSource::Bundled => Ok(bundled.to_string()),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Bundled => write!(f, "bundled input"),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+// Used by the part_1/part_2 binaries: the first command line argument picks the
+// input source. There's nothing useful a binary can do without its input, so
+// this reports the problem and exits rather than returning an error.
+pub fn load(bundled: &str) -> String {
+    let source = InputSource::from_arg(env::args().nth(1).as_deref());
+
+    source.read(bundled).unwrap_or_else(|err| {
+        eprintln!("Could not read {source}: {err}");
+        process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_argument_is_bundled() {
+        assert_eq!(InputSource::Bundled, InputSource::from_arg(None));
+    }
+
+    #[test]
+    fn dash_is_stdin() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg(Some("-")));
+    }
+
+    #[test]
+    fn anything_else_is_a_file() {
+        assert_eq!(
+            InputSource::File(PathBuf::from("src/sample_input.txt")),
+            InputSource::from_arg(Some("src/sample_input.txt"))
+        );
+    }
+
+    #[test]
+    fn read_bundled() {
+        assert_eq!("1abc2", InputSource::Bundled.read("1abc2").unwrap());
+    }
+
+    #[test]
+    fn read_file() {
+        let input = InputSource::File(PathBuf::from("Cargo.toml"))
+            .read("")
+            .unwrap();
+        assert!(input.starts_with("[package]"));
+    }
+
+    #[test]
+    fn read_missing_file() {
+        let result = InputSource::File(PathBuf::from("does_not_exist.txt")).read("");
+        assert_eq!(io::ErrorKind::NotFound, result.unwrap_err().kind());
+    }
+}
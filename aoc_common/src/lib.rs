@@ -0,0 +1,6 @@
+pub mod diagnostic;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod solution;
@@ -0,0 +1,89 @@
+use crate::error::{ParseError, ParseErrorKind};
+use regex::Regex;
+use std::str::FromStr;
+
+// Parses a whitespace separated list of numbers, e.g. "41 48 83  6 17"
+pub fn numbers<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    text.split_whitespace()
+        .map(|n| number(n).map_err(|err| err.within(text, n)))
+        .collect()
+}
+
+pub fn number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(ParseErrorKind::InvalidNumber, text, text))
+}
+
+// Matches `text` against `regex` and returns its N capture groups, which is
+// what we almost always want from the `captures(...).extract()` dance.
+pub fn captures<'a, const N: usize>(
+    regex: &Regex,
+    text: &'a str,
+) -> Result<[&'a str; N], ParseError> {
+    match regex.captures(text) {
+        Some(captures) => Ok(captures.extract().1),
+        None => Err(ParseError::new(
+            ParseErrorKind::NoMatch {
+                pattern: regex.as_str().to_string(),
+            },
+            text,
+            text,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_with_extra_whitespace() {
+        let result: Vec<u32> = numbers(" 83 86  6 31 17  9 48 53").unwrap();
+        assert_eq!(vec![83, 86, 6, 31, 17, 9, 48, 53], result);
+    }
+
+    #[test]
+    fn negative_numbers() {
+        let result: Vec<i32> = numbers("10 -3 0").unwrap();
+        assert_eq!(vec![10, -3, 0], result);
+    }
+
+    #[test]
+    fn invalid_number() {
+        let result = numbers::<u32>("1 2 three");
+        assert_eq!(
+            Err(ParseError {
+                kind: ParseErrorKind::InvalidNumber,
+                line: 1,
+                column: 5,
+                text: "three".to_string()
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn captures_match() {
+        let regex = Regex::new(r"^Game ([0-9]+): (.+)$").unwrap();
+        let [id, rest] = captures(&regex, "Game 12: 3 blue").unwrap();
+        assert_eq!("12", id);
+        assert_eq!("3 blue", rest);
+    }
+
+    #[test]
+    fn captures_no_match() {
+        let regex = Regex::new(r"^Game ([0-9]+): (.+)$").unwrap();
+        let result = captures::<2>(&regex, "Gmae 12: 3 blue");
+        assert_eq!(
+            Err(ParseError {
+                kind: ParseErrorKind::NoMatch {
+                    pattern: r"^Game ([0-9]+): (.+)$".to_string()
+                },
+                line: 1,
+                column: 1,
+                text: "Gmae 12: 3 blue".to_string()
+            }),
+            result
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use crate::diagnostic;
+use crate::error::ParseError;
+use std::fmt::Display;
+use std::process;
+
+// Implemented by every day so tools like the aoc runner can treat them all the
+// same way: parse the input once, then solve either part from the parsed puzzle.
+// Parsing only checks what both parts need, so a part can still find its input
+// unsuitable and report where, the same way parsing does.
+pub trait Solution {
+    const DAY: u8;
+    const INPUT: &'static str;
+
+    type Puzzle;
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Puzzle, ParseError>;
+
+    fn part_1(puzzle: &Self::Puzzle) -> Result<Self::Answer, ParseError>;
+
+    fn part_2(puzzle: &Self::Puzzle) -> Result<Self::Answer, ParseError>;
+
+    fn solve(part: u8, input: &str) -> Result<Self::Answer, ParseError> {
+        let puzzle = Self::parse(input)?;
+        Self::solve_parsed(part, &puzzle)
+    }
+
+    fn solve_parsed(part: u8, puzzle: &Self::Puzzle) -> Result<Self::Answer, ParseError> {
+        match part {
+            1 => Self::part_1(puzzle),
+            2 => Self::part_2(puzzle),
+            _ => panic!("Puzzles only have two parts, not {part}"),
+        }
+    }
+}
+
+// Used by the part_1/part_2 binaries, which can't do anything useful with
+// input that doesn't suit the part, so this points out the problem and exits.
+pub fn solve_or_exit<S: Solution>(part: u8, input: &str) -> S::Answer {
+    S::solve(part, input).unwrap_or_else(|err| {
+        eprintln!("Invalid input for day {}", S::DAY);
+        eprint!("{}", diagnostic::render(input, &err));
+        process::exit(1);
+    })
+}
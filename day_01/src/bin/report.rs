@@ -0,0 +1,95 @@
+use aoc_common::diagnostic;
+use aoc_common::input::InputSource;
+use aoc_common::solution::Solution;
+use clap::{Parser, ValueEnum};
+use day_01::calibration::{CalibrationDecoder, DigitRules};
+use day_01::report;
+use day_01::vocabulary::Vocabulary;
+use day_01::Day01;
+use std::fs;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(about = "Show how each line of a calibration document is decoded")]
+struct Cli {
+    /// Calibration document to decode (`-` reads stdin, the bundled input is used when omitted)
+    input: Option<String>,
+
+    /// Decode using the rules for this part
+    #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Words that count as digits for part 2
+    #[arg(long, value_enum, default_value_t = BuiltInVocabulary::English, conflicts_with = "word_list")]
+    vocabulary: BuiltInVocabulary,
+
+    /// File of words that count as digits for part 2, with a word and its digit on each line
+    #[arg(long)]
+    word_list: Option<String>,
+
+    /// Print CSV instead of plain text
+    #[arg(long)]
+    csv: bool,
+}
+
+#[derive(Clone, ValueEnum)]
+enum BuiltInVocabulary {
+    English,
+    EnglishWithZero,
+    German,
+    Spanish,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let source = InputSource::from_arg(cli.input.as_deref());
+    let document = match source.read(Day01::INPUT) {
+        Ok(document) => document,
+        Err(err) => {
+            eprintln!("Could not read {source}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let vocabulary = match &cli.word_list {
+        Some(path) => {
+            let word_list = match fs::read_to_string(path) {
+                Ok(word_list) => word_list,
+                Err(err) => {
+                    eprintln!("Could not read {path}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            match Vocabulary::from_word_list(&word_list) {
+                Ok(vocabulary) => vocabulary,
+                Err(err) => {
+                    eprintln!("Invalid word list {path}");
+                    eprint!("{}", diagnostic::render(&word_list, &err));
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        None => match cli.vocabulary {
+            BuiltInVocabulary::English => Vocabulary::english(),
+            BuiltInVocabulary::EnglishWithZero => Vocabulary::english_with_zero(),
+            BuiltInVocabulary::German => Vocabulary::german(),
+            BuiltInVocabulary::Spanish => Vocabulary::spanish(),
+        },
+    };
+
+    let rules = match cli.part {
+        1 => DigitRules::DigitsOnly,
+        _ => DigitRules::DigitsAndWords(vocabulary),
+    };
+
+    let reports = report::report(&CalibrationDecoder::new(rules), &document);
+    if cli.csv {
+        print!("{}", report::to_csv(&reports));
+    } else {
+        print!("{}", report::to_text(&reports));
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,51 @@
+use clap::Parser;
+use day_01::calibration::{CalibrationDecoder, DigitRules};
+use day_01::stream;
+use day_01::vocabulary::Vocabulary;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(about = "Sum the calibration values of a document too big to fit in memory")]
+struct Cli {
+    /// Calibration document, optionally gzipped (`-` reads stdin)
+    document: PathBuf,
+
+    /// Decode using the rules for this part
+    #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let reader = if cli.document.as_os_str() == "-" {
+        stream::read_possibly_gzipped(io::stdin().lock())
+    } else {
+        stream::open(&cli.document)
+    };
+    let reader = match reader {
+        Ok(reader) => reader,
+        Err(err) => {
+            eprintln!("Could not read {}: {err}", cli.document.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let rules = match cli.part {
+        1 => DigitRules::DigitsOnly,
+        _ => DigitRules::DigitsAndWords(Vocabulary::english()),
+    };
+
+    match stream::calibration_sum(&CalibrationDecoder::new(rules), reader) {
+        Ok(sum) => {
+            println!("{sum}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Could not sum {}: {err}", cli.document.display());
+            ExitCode::FAILURE
+        }
+    }
+}
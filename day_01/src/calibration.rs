@@ -0,0 +1,203 @@
+use crate::digit_words::{DigitScanner, Token};
+use crate::vocabulary::Vocabulary;
+use aoc_common::error::ParseError;
+
+// Which characters on a line count as digits
+#[derive(Clone, PartialEq, Debug)]
+pub enum DigitRules {
+    // Only 0-9, as in part 1
+    DigitsOnly,
+    // Spelled out digits as well, like "one" in part 2
+    DigitsAndWords(Vocabulary),
+}
+
+// Recovers calibration values from the lines of a calibration document: the
+// first and last digit on a line, read as a two digit number
+pub struct CalibrationDecoder {
+    scanner: DigitScanner,
+    // What a line without a calibration value was missing, for errors
+    missing: &'static str,
+}
+
+impl CalibrationDecoder {
+    pub fn new(rules: DigitRules) -> CalibrationDecoder {
+        let (scanner, missing) = match rules {
+            DigitRules::DigitsOnly => (
+                DigitScanner::new([]),
+                "a line containing a numeral, like \"7\"",
+            ),
+            DigitRules::DigitsAndWords(vocabulary) => (
+                DigitScanner::new(vocabulary.words()),
+                "a line containing a digit",
+            ),
+        };
+
+        CalibrationDecoder { scanner, missing }
+    }
+
+    // Fails on the first line without a digit, since it has no calibration value
+    pub fn document_sum(&self, document: &str) -> Result<usize, ParseError> {
+        let mut sum = 0;
+
+        for (i, line) in document.lines().enumerate() {
+            sum += self
+                .calibration_value(line)
+                .ok_or_else(|| ParseError::expected(self.missing, line, line).on_line(i + 1))?;
+        }
+
+        Ok(sum)
+    }
+
+    // None for a line without any digits. Only looks as far into the line as
+    // it needs to from each end, and doesn't allocate.
+    pub fn calibration_value(&self, line: &str) -> Option<usize> {
+        let first = self.first_digit(line)?;
+        let last = self.last_digit(line)?;
+
+        Some(first.digit * 10 + last.digit)
+    }
+
+    pub fn first_digit(&self, line: &str) -> Option<Token> {
+        self.scanner.first(line)
+    }
+
+    pub fn last_digit(&self, line: &str) -> Option<Token> {
+        self.scanner.last(line)
+    }
+
+    // Every digit on the line, in order
+    pub fn digits(&self, line: &str) -> Vec<usize> {
+        self.tokens(line).iter().map(|token| token.digit).collect()
+    }
+
+    // Every digit on the line along with where it was found, in the order
+    // they start
+    pub fn tokens(&self, line: &str) -> Vec<Token> {
+        let mut tokens: Vec<Token> = self.scanner.scan(line).collect();
+        tokens.sort_by_key(|token| (token.start, token.end));
+        tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits_only(line: &str) -> usize {
+        CalibrationDecoder::new(DigitRules::DigitsOnly)
+            .calibration_value(line)
+            .unwrap()
+    }
+
+    fn digits_and_words(line: &str) -> usize {
+        CalibrationDecoder::new(DigitRules::DigitsAndWords(Vocabulary::english()))
+            .calibration_value(line)
+            .unwrap()
+    }
+
+    #[test]
+    fn string_to_number_1() {
+        assert_eq!(12, digits_only("1abc2"));
+        assert_eq!(12, digits_and_words("1abc2"));
+    }
+
+    #[test]
+    fn string_to_number_2() {
+        assert_eq!(38, digits_only("pqr3stu8vwx"));
+        assert_eq!(38, digits_and_words("pqr3stu8vwx"));
+    }
+
+    #[test]
+    fn string_to_number_3() {
+        assert_eq!(15, digits_only("a1b2c3d4e5f"));
+        assert_eq!(15, digits_and_words("a1b2c3d4e5f"));
+    }
+
+    #[test]
+    fn string_to_number_4() {
+        assert_eq!(77, digits_only("treb7uchet"));
+        assert_eq!(77, digits_and_words("treb7uchet"));
+    }
+
+    #[test]
+    fn string_to_number_5() {
+        assert_eq!(11, digits_only("two1nine"));
+        assert_eq!(29, digits_and_words("two1nine"));
+    }
+
+    #[test]
+    fn string_to_number_6() {
+        assert_eq!(83, digits_and_words("eightwothree"));
+    }
+
+    #[test]
+    fn string_to_number_7() {
+        assert_eq!(13, digits_and_words("abcone2threexyz"));
+    }
+
+    #[test]
+    fn string_to_number_8() {
+        assert_eq!(24, digits_and_words("xtwone3four"));
+    }
+
+    #[test]
+    fn string_to_number_9() {
+        assert_eq!(42, digits_and_words("4nineeightseven2"));
+    }
+
+    #[test]
+    fn string_to_number_10() {
+        assert_eq!(24, digits_only("zoneight234"));
+        assert_eq!(14, digits_and_words("zoneight234"));
+    }
+
+    #[test]
+    fn string_to_number_11() {
+        assert_eq!(77, digits_only("7pqrstsixteen"));
+        assert_eq!(76, digits_and_words("7pqrstsixteen"));
+    }
+
+    #[test]
+    fn string_to_number_12() {
+        assert_eq!(82, digits_and_words("eightwo"));
+    }
+
+    #[test]
+    fn spanish() {
+        let decoder = CalibrationDecoder::new(DigitRules::DigitsAndWords(Vocabulary::spanish()));
+        assert_eq!(Some(31), decoder.calibration_value("xtresiete1unox"));
+        assert_eq!(Some(70), decoder.calibration_value("siete cero"));
+    }
+
+    #[test]
+    fn other_numeric_characters_are_not_digits() {
+        assert_eq!(12, digits_only("1½٣2"));
+    }
+
+    #[test]
+    fn test_calibration_document_sum() {
+        let document = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+        let decoder = CalibrationDecoder::new(DigitRules::DigitsOnly);
+        assert_eq!(Ok(142), decoder.document_sum(document));
+    }
+
+    #[test]
+    fn line_without_digits() {
+        let decoder = CalibrationDecoder::new(DigitRules::DigitsOnly);
+        assert_eq!(None, decoder.calibration_value("eightwothree"));
+
+        let error = decoder.document_sum("1abc2\neightwothree\n").unwrap_err();
+        assert_eq!(2, error.line);
+        assert_eq!("eightwothree", error.text);
+    }
+
+    #[test]
+    fn very_long_line() {
+        let line = format!("x7{}3four", "y".repeat(1_000_000));
+        let decoder = CalibrationDecoder::new(DigitRules::DigitsAndWords(Vocabulary::english()));
+        assert_eq!(Some(74), decoder.calibration_value(&line));
+    }
+}
@@ -1,93 +1,194 @@
+use aho_corasick::{AhoCorasick, Anchored, Input, StartKind};
 
-    // TODO: Can this actual just be an enum?
-    pub static DIGIT_WORDS: phf::Map<&str, usize> = phf::phf_map! {
-        "one" => 1,
-        "two" => 2,
-        "three" => 3,
-        "four" => 4,
-        "five" => 5,
-        "six" => 6,
-        "seven" => 7,
-        "eight" => 8,
-        "nine" => 9,
-    };
-
-    pub fn find_digit_word_starting_at_position(i: usize, haystack: &str) -> Option<&str> {
-        let mut candidate: &str;
-
-        for j in i..haystack.len() {
-            candidate = &haystack[i..=j];
-
-            if DIGIT_WORDS.contains_key(candidate) {
-                return Some(candidate);
-            }
-        }
+// A digit found on a line. `start` and `end` are byte offsets, and always
+// fall on character boundaries.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Token {
+    pub start: usize,
+    pub end: usize,
+    pub digit: usize,
+}
 
-        None
-    }
-
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-
-        #[test]
-        fn happy_case() {
-            let result_0 = find_digit_word_starting_at_position(0, "zero");
-            let result_1 = find_digit_word_starting_at_position(0, "one");
-            let result_2 = find_digit_word_starting_at_position(0, "two");
-            let result_3 = find_digit_word_starting_at_position(0, "three");
-            let result_4 = find_digit_word_starting_at_position(0, "four");
-            let result_5 = find_digit_word_starting_at_position(0, "five");
-            let result_6 = find_digit_word_starting_at_position(0, "six");
-            let result_7 = find_digit_word_starting_at_position(0, "seven");
-            let result_8 = find_digit_word_starting_at_position(0, "eight");
-            let result_9 = find_digit_word_starting_at_position(0, "nine");
-
-            assert_eq!(Some("zero"), result_0);
-            assert_eq!(Some("one"), result_1);
-            assert_eq!(Some("two"), result_2);
-            assert_eq!(Some("three"), result_3);
-            assert_eq!(Some("four"), result_4);
-            assert_eq!(Some("five"), result_5);
-            assert_eq!(Some("six"), result_6);
-            assert_eq!(Some("seven"), result_7);
-            assert_eq!(Some("eight"), result_8);
-            assert_eq!(Some("nine"), result_9);
-        }
+// Finds every digit and digit word on a line in a single pass, including ones
+// that overlap like the "eight" and "two" in "eightwo"
+pub struct DigitScanner {
+    automaton: AhoCorasick,
+    // The digit each of the automaton's patterns stands for
+    digits: Vec<usize>,
+}
 
-        #[test]
-        fn happy_case_3() {
-            let result = find_digit_word_starting_at_position(0, "eightwothree");
-            assert_eq!(Some("eight"), result)
-        }
+impl DigitScanner {
+    // Scans for 0-9 plus the given words
+    pub fn new<'a>(words: impl IntoIterator<Item = (&'a str, usize)>) -> DigitScanner {
+        let mut patterns: Vec<String> = (0..10).map(|digit| digit.to_string()).collect();
+        let mut digits: Vec<usize> = (0..10).collect();
 
-        #[test]
-        fn happy_case_4() {
-            let result = find_digit_word_starting_at_position(4, "eightwothree");
-            assert_eq!(Some("two"), result)
+        for (word, digit) in words {
+            patterns.push(word.to_string());
+            digits.push(digit);
         }
 
-        #[test]
-        fn happy_case_5() {
-            let result = find_digit_word_starting_at_position(7, "eightwothree");
-            assert_eq!(Some("three"), result)
-        }
+        // Anchored searches let `first` and `last` check one position at a time
+        let automaton = AhoCorasick::builder()
+            .start_kind(StartKind::Both)
+            .build(patterns)
+            .expect("digit patterns should build");
 
-        #[test]
-        fn sad_case() {
-            let result = find_digit_word_starting_at_position(1, "one");
-            assert_eq!(None, result);
-        }
+        DigitScanner { automaton, digits }
+    }
+
+    // Matches are found in the order they end, so a long word can come after
+    // a shorter one that starts later
+    pub fn scan<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Token> + 'a {
+        self.automaton
+            .find_overlapping_iter(line)
+            .map(|found| self.token(found))
+    }
+
+    // The digit that starts first, searching forwards from the start of the line
+    pub fn first(&self, line: &str) -> Option<Token> {
+        line.char_indices()
+            .find_map(|(start, _)| self.token_starting_at(line, start))
+    }
+
+    // The digit that starts last, searching backwards from the end of the
+    // line. Words overlapping the end of an earlier word are still found,
+    // e.g. the "two" in "eightwo".
+    pub fn last(&self, line: &str) -> Option<Token> {
+        line.char_indices()
+            .rev()
+            .find_map(|(start, _)| self.token_starting_at(line, start))
+    }
+
+    // The shortest digit or word starting exactly at `start`
+    fn token_starting_at(&self, line: &str, start: usize) -> Option<Token> {
+        let input = Input::new(line)
+            .span(start..line.len())
+            .anchored(Anchored::Yes);
+        self.automaton.find(input).map(|found| self.token(found))
+    }
 
-        #[test]
-        fn sad_case_2() {
-            let result = find_digit_word_starting_at_position(0, "o1ne");
-            assert_eq!(None, result);
+    fn token(&self, found: aho_corasick::Match) -> Token {
+        Token {
+            start: found.start(),
+            end: found.end(),
+            digit: self.digits[found.pattern().as_usize()],
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vocabulary::Vocabulary;
 
-        #[test]
-        fn sad_case_3() {
-            let result = find_digit_word_starting_at_position(0, "1one");
-            assert_eq!(None, result);
+    fn english() -> DigitScanner {
+        DigitScanner::new(Vocabulary::english().words())
+    }
+
+    fn digits(scanner: &DigitScanner, line: &str) -> Vec<usize> {
+        scanner.scan(line).map(|token| token.digit).collect()
+    }
+
+    #[test]
+    fn happy_case() {
+        let scanner = DigitScanner::new(Vocabulary::english_with_zero().words());
+
+        for (digit, word) in [
+            "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ]
+        .iter()
+        .enumerate()
+        {
+            assert_eq!(vec![digit], digits(&scanner, word));
         }
     }
+
+    #[test]
+    fn happy_case_3() {
+        let tokens: Vec<Token> = english().scan("eightwothree").collect();
+        assert_eq!(
+            vec![
+                Token {
+                    start: 0,
+                    end: 5,
+                    digit: 8
+                },
+                Token {
+                    start: 4,
+                    end: 7,
+                    digit: 2
+                },
+                Token {
+                    start: 7,
+                    end: 12,
+                    digit: 3
+                },
+            ],
+            tokens
+        );
+    }
+
+    #[test]
+    fn overlapping_words() {
+        assert_eq!(vec![8, 2], digits(&english(), "eightwo"));
+        assert_eq!(vec![2, 1], digits(&english(), "twone"));
+        assert_eq!(vec![3, 8], digits(&english(), "threeight"));
+    }
+
+    #[test]
+    fn digits_only() {
+        let scanner = DigitScanner::new([]);
+        assert_eq!(vec![1, 2], digits(&scanner, "one1two2"));
+    }
+
+    #[test]
+    fn sad_case() {
+        assert_eq!(Vec::<usize>::new(), digits(&english(), "ne"));
+    }
+
+    #[test]
+    fn sad_case_2() {
+        assert_eq!(vec![1], digits(&english(), "o1ne"));
+    }
+
+    #[test]
+    fn sad_case_3() {
+        assert_eq!(vec![1, 1], digits(&english(), "1one"));
+    }
+
+    #[test]
+    fn zero_is_not_in_the_puzzle_vocabulary() {
+        assert_eq!(vec![1], digits(&english(), "zero1"));
+    }
+
+    #[test]
+    fn german() {
+        let scanner = DigitScanner::new(Vocabulary::german().words());
+        assert_eq!(vec![5, 3, 1], digits(&scanner, "fünfdreins"));
+    }
+
+    #[test]
+    fn first_and_last() {
+        let scanner = english();
+        assert_eq!(Some(8), scanner.first("xeightwo").map(|token| token.digit));
+        assert_eq!(Some(2), scanner.last("xeightwo").map(|token| token.digit));
+        assert_eq!(Some(1), scanner.last("1fiv").map(|token| token.digit));
+    }
+
+    #[test]
+    fn first_and_last_without_digits() {
+        let scanner = english();
+        assert_eq!(None, scanner.first("zero"));
+        assert_eq!(None, scanner.last(""));
+    }
+
+    #[test]
+    fn multi_byte_characters() {
+        let line = "ñone²٣two";
+        let tokens: Vec<Token> = english().scan(line).collect();
+        assert_eq!(vec![1, 2], digits(&english(), line));
+        assert_eq!(2, tokens[0].start);
+        assert_eq!(9, tokens[1].start);
+    }
+}
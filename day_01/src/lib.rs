@@ -1 +1,75 @@
-pub mod digit_words;
\ No newline at end of file
+pub mod calibration;
+pub mod digit_words;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod report;
+pub mod stream;
+pub mod vocabulary;
+
+use aoc_common::error::ParseError;
+use aoc_common::solution::Solution;
+use calibration::{CalibrationDecoder, DigitRules};
+use vocabulary::Vocabulary;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Puzzle = String;
+    type Answer = usize;
+
+    // The parts disagree about what counts as a digit, so lines are only
+    // decoded, and checked, by the part reading them
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_1(document: &String) -> Result<usize, ParseError> {
+        document_sum(&CalibrationDecoder::new(DigitRules::DigitsOnly), document)
+    }
+
+    fn part_2(document: &String) -> Result<usize, ParseError> {
+        document_sum(
+            &CalibrationDecoder::new(DigitRules::DigitsAndWords(Vocabulary::english())),
+            document,
+        )
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+fn document_sum(decoder: &CalibrationDecoder, document: &str) -> Result<usize, ParseError> {
+    decoder.document_sum(document)
+}
+
+#[cfg(feature = "parallel")]
+fn document_sum(decoder: &CalibrationDecoder, document: &str) -> Result<usize, ParseError> {
+    decoder.par_document_sum(document)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_without_a_digit() {
+        let error = Day01::solve(2, "1abc2\nabc\n").unwrap_err();
+        assert_eq!(2, error.line);
+        assert_eq!(
+            "line 2, column 1: expected a line containing a digit, found \"abc\"",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn line_without_a_numeral() {
+        let error = Day01::solve(1, "1abc2\ntwo\n3\n").unwrap_err();
+        assert_eq!(2, error.line);
+        assert_eq!(
+            "line 2, column 1: expected a line containing a numeral, like \"7\", found \"two\"",
+            error.to_string()
+        );
+        assert_eq!(Ok(12 + 22 + 33), Day01::solve(2, "1abc2\ntwo\n3\n"));
+    }
+}
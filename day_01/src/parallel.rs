@@ -0,0 +1,137 @@
+use crate::calibration::CalibrationDecoder;
+use aoc_common::error::ParseError;
+use rayon::prelude::*;
+
+// Small documents aren't worth spreading across threads
+const MIN_CHUNK_BYTES: usize = 64 * 1024;
+
+impl CalibrationDecoder {
+    // The same as `document_sum`, but decodes chunks of the document on
+    // different threads. Errors are still reported for the first bad line.
+    pub fn par_document_sum(&self, document: &str) -> Result<usize, ParseError> {
+        let chunks = chunks(document, rayon::current_num_threads() * 4);
+        let sums: Vec<Result<usize, ParseError>> = chunks
+            .par_iter()
+            .map(|chunk| self.document_sum(chunk))
+            .collect();
+
+        let mut total = 0;
+        for (i, sum) in sums.into_iter().enumerate() {
+            // Line numbers in an error are relative to its chunk
+            total += sum.map_err(|err| {
+                let earlier_lines: usize =
+                    chunks[..i].iter().map(|chunk| chunk.lines().count()).sum();
+                let line = err.line + earlier_lines;
+                err.on_line(line)
+            })?;
+        }
+
+        Ok(total)
+    }
+}
+
+// Splits `document` into about `count` chunks, only ever between lines
+fn chunks(document: &str, count: usize) -> Vec<&str> {
+    let chunk_size = (document.len() / count.max(1)).max(MIN_CHUNK_BYTES);
+    let mut chunks = vec![];
+    let mut rest = document;
+
+    while rest.len() > chunk_size {
+        // Searching bytes rather than chars, as chunk_size needn't be on a char
+        // boundary. A newline always is.
+        let end = match rest.as_bytes()[chunk_size..]
+            .iter()
+            .position(|b| *b == b'\n')
+        {
+            Some(newline) => chunk_size + newline + 1,
+            None => rest.len(),
+        };
+        let (chunk, remainder) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = remainder;
+    }
+
+    if !rest.is_empty() {
+        chunks.push(rest);
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calibration::DigitRules;
+    use crate::vocabulary::Vocabulary;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    // Lines of random letters (some of them Greek, to make sure chunks are
+    // split on character boundaries), digits and digit words. Every line ends
+    // with a numeral so it decodes under either set of rules.
+    fn random_document(rng: &mut StdRng, lines: usize) -> String {
+        let mut document = String::new();
+
+        for _ in 0..lines {
+            for _ in 0..rng.gen_range(0..8) {
+                match rng.gen_range(0..4) {
+                    0 => document.push(rng.gen_range('a'..='z')),
+                    1 => document.push(rng.gen_range('α'..='ω')),
+                    2 => document.push_str(WORDS[rng.gen_range(0..WORDS.len())]),
+                    _ => document.push(rng.gen_range('1'..='9')),
+                }
+            }
+            document.push(rng.gen_range('1'..='9'));
+            document.push('\n');
+        }
+
+        document
+    }
+
+    #[test]
+    fn chunks_split_between_lines() {
+        let document = "1abc2\n".repeat(50_000);
+        let chunks = chunks(&document, 8);
+
+        assert!(chunks.len() > 1);
+        assert_eq!(document, chunks.concat());
+        assert!(chunks.iter().all(|chunk| chunk.ends_with('\n')));
+    }
+
+    #[test]
+    fn matches_sequential_sum() {
+        let mut rng = StdRng::seed_from_u64(2023);
+
+        for rules in [
+            DigitRules::DigitsOnly,
+            DigitRules::DigitsAndWords(Vocabulary::english()),
+        ] {
+            let decoder = CalibrationDecoder::new(rules);
+
+            for lines in [0, 1, 1_000, 200_000] {
+                let document = random_document(&mut rng, lines);
+                assert_eq!(
+                    decoder.document_sum(&document),
+                    decoder.par_document_sum(&document)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reports_first_bad_line() {
+        let mut document = "1abc2\n".repeat(100_000);
+        document.push_str("abc\n");
+        document.push_str(&"1abc2\n".repeat(100_000));
+        document.push_str("def\n");
+
+        let decoder = CalibrationDecoder::new(DigitRules::DigitsOnly);
+        let error = decoder.par_document_sum(&document).unwrap_err();
+        assert_eq!(100_001, error.line);
+        assert_eq!(decoder.document_sum(&document), Err(error));
+    }
+}
@@ -0,0 +1,163 @@
+use crate::calibration::CalibrationDecoder;
+use crate::digit_words::Token;
+
+// How one line of a calibration document was decoded, for working out why an
+// answer is wrong. Diffing the reports for two sets of rules shows exactly
+// which lines they disagree on.
+#[derive(PartialEq, Debug)]
+pub struct LineReport<'a> {
+    pub line_number: usize,
+    pub line: &'a str,
+    // Every digit found, in the order they start
+    pub tokens: Vec<Token>,
+    pub first: Option<Token>,
+    pub last: Option<Token>,
+    pub value: Option<usize>,
+}
+
+impl<'a> LineReport<'a> {
+    pub fn new(decoder: &CalibrationDecoder, line_number: usize, line: &'a str) -> LineReport<'a> {
+        LineReport {
+            line_number,
+            line,
+            tokens: decoder.tokens(line),
+            first: decoder.first_digit(line),
+            last: decoder.last_digit(line),
+            value: decoder.calibration_value(line),
+        }
+    }
+
+    // e.g. "two@1", the token as written and the column it starts in
+    fn describe(&self, token: &Token) -> String {
+        let column = self.line[..token.start].chars().count() + 1;
+        format!("{}@{column}", &self.line[token.start..token.end])
+    }
+
+    fn describe_all(&self) -> String {
+        let tokens: Vec<String> = self
+            .tokens
+            .iter()
+            .map(|token| self.describe(token))
+            .collect();
+        tokens.join(" ")
+    }
+
+    fn describe_digit(&self, token: &Option<Token>) -> String {
+        match token {
+            Some(token) => format!("{} ({})", token.digit, self.describe(token)),
+            None => "none".to_string(),
+        }
+    }
+}
+
+pub fn report<'a>(decoder: &CalibrationDecoder, document: &'a str) -> Vec<LineReport<'a>> {
+    document
+        .lines()
+        .enumerate()
+        .map(|(i, line)| LineReport::new(decoder, i + 1, line))
+        .collect()
+}
+
+pub fn to_text(reports: &[LineReport]) -> String {
+    let mut text = String::new();
+
+    for report in reports {
+        let value = match report.value {
+            Some(value) => value.to_string(),
+            None => "none".to_string(),
+        };
+
+        text.push_str(&format!("line {}: {}\n", report.line_number, report.line));
+        text.push_str(&format!("  tokens: {}\n", report.describe_all()));
+        text.push_str(&format!(
+            "  first: {}, last: {}, value: {value}\n",
+            report.describe_digit(&report.first),
+            report.describe_digit(&report.last)
+        ));
+    }
+
+    text
+}
+
+// Columns are line,text,tokens,first,last,value. Digits and values are left
+// empty for lines without any digits.
+pub fn to_csv(reports: &[LineReport]) -> String {
+    let mut csv = String::from("line,text,tokens,first,last,value\n");
+
+    for report in reports {
+        let digit = |token: &Option<Token>| token.map(|token| token.digit.to_string());
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            report.line_number,
+            csv_field(report.line),
+            csv_field(&report.describe_all()),
+            digit(&report.first).unwrap_or_default(),
+            digit(&report.last).unwrap_or_default(),
+            report
+                .value
+                .map(|value| value.to_string())
+                .unwrap_or_default()
+        ));
+    }
+
+    csv
+}
+
+// Quotes fields that would otherwise break the CSV
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calibration::DigitRules;
+    use crate::vocabulary::Vocabulary;
+
+    fn part_2_decoder() -> CalibrationDecoder {
+        CalibrationDecoder::new(DigitRules::DigitsAndWords(Vocabulary::english()))
+    }
+
+    #[test]
+    fn text_report() {
+        let reports = report(&part_2_decoder(), "two1nine\nabc");
+        assert_eq!(
+            "line 1: two1nine\n  \
+             tokens: two@1 1@4 nine@5\n  \
+             first: 2 (two@1), last: 9 (nine@5), value: 29\n\
+             line 2: abc\n  \
+             tokens: \n  \
+             first: none, last: none, value: none\n",
+            to_text(&reports)
+        );
+    }
+
+    #[test]
+    fn overlapping_tokens() {
+        let reports = report(&part_2_decoder(), "xtwone3four");
+        assert_eq!("two@2 one@4 3@7 four@8", reports[0].describe_all());
+        assert_eq!(Some(24), reports[0].value);
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        let reports = report(&part_2_decoder(), "ñone");
+        assert_eq!("one@2", reports[0].describe_all());
+    }
+
+    #[test]
+    fn csv_report() {
+        let decoder = CalibrationDecoder::new(DigitRules::DigitsOnly);
+        let reports = report(&decoder, "two1nine\n\"a\",b\n");
+        assert_eq!(
+            "line,text,tokens,first,last,value\n\
+             1,two1nine,1@4,1,1,11\n\
+             2,\"\"\"a\"\",b\",,,,\n",
+            to_csv(&reports)
+        );
+    }
+}
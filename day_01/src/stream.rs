@@ -0,0 +1,150 @@
+use crate::calibration::CalibrationDecoder;
+use aoc_common::error::ParseError;
+use flate2::bufread::MultiGzDecoder;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+// The first two bytes of every gzip file
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    // A line without any digits
+    Parse(ParseError),
+    // The sum got too big for a u64 at this line
+    Overflow { line: usize },
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "could not read the document: {err}"),
+            StreamError::Parse(err) => write!(f, "{err}"),
+            StreamError::Overflow { line } => {
+                write!(f, "line {line}: the calibration sum is too large")
+            }
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> StreamError {
+        StreamError::Io(err)
+    }
+}
+
+// Sums the calibration values of a document one line at a time, so only the
+// current line is ever held in memory
+pub fn calibration_sum(
+    decoder: &CalibrationDecoder,
+    mut reader: impl BufRead,
+) -> Result<u64, StreamError> {
+    let mut sum: u64 = 0;
+    let mut line = String::new();
+    let mut line_number = 0;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(sum);
+        }
+        line_number += 1;
+
+        // Same line endings as str::lines
+        let text = line.strip_suffix('\n').unwrap_or(&line);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+
+        let value = decoder.calibration_value(text).ok_or_else(|| {
+            StreamError::Parse(
+                ParseError::expected("a line containing a digit", text, text).on_line(line_number),
+            )
+        })?;
+        sum = add_value(sum, value, line_number)?;
+    }
+}
+
+fn add_value(sum: u64, value: usize, line_number: usize) -> Result<u64, StreamError> {
+    sum.checked_add(value as u64)
+        .ok_or(StreamError::Overflow { line: line_number })
+}
+
+// Opens a calibration document, decompressing it on the fly if it's gzipped
+pub fn open(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    read_possibly_gzipped(BufReader::new(File::open(path)?))
+}
+
+// Gzipped input is recognised by its first two bytes rather than trusting a
+// file extension, which stdin doesn't have anyway
+pub fn read_possibly_gzipped<'a>(
+    mut reader: impl BufRead + 'a,
+) -> io::Result<Box<dyn BufRead + 'a>> {
+    if reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+        Ok(Box::new(BufReader::new(MultiGzDecoder::new(reader))))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calibration::DigitRules;
+    use crate::vocabulary::Vocabulary;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    const SAMPLE: &str = include_str!("sample_input_2.txt");
+
+    fn part_2_decoder() -> CalibrationDecoder {
+        CalibrationDecoder::new(DigitRules::DigitsAndWords(Vocabulary::english()))
+    }
+
+    #[test]
+    fn matches_document_sum() {
+        let sum = calibration_sum(&part_2_decoder(), SAMPLE.as_bytes()).unwrap();
+        assert_eq!(281, sum);
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let sum = calibration_sum(&part_2_decoder(), "two1nine\r\nxtwone3four\r\n".as_bytes());
+        assert_eq!(53, sum.unwrap());
+    }
+
+    #[test]
+    fn gzipped_document() {
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(SAMPLE.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let reader = read_possibly_gzipped(compressed.as_slice()).unwrap();
+        assert_eq!(281, calibration_sum(&part_2_decoder(), reader).unwrap());
+    }
+
+    #[test]
+    fn line_without_digits() {
+        let decoder = CalibrationDecoder::new(DigitRules::DigitsOnly);
+        let result = calibration_sum(&decoder, "1abc2\neightwothree\n".as_bytes());
+
+        let Err(StreamError::Parse(error)) = result else {
+            panic!("expected a parse error, got {result:?}");
+        };
+        assert_eq!(2, error.line);
+    }
+
+    #[test]
+    fn overflow() {
+        assert!(matches!(
+            add_value(u64::MAX - 10, 11, 7),
+            Err(StreamError::Overflow { line: 7 })
+        ));
+        assert_eq!(u64::MAX, add_value(u64::MAX - 10, 10, 7).unwrap());
+    }
+}
@@ -0,0 +1,137 @@
+use aoc_common::error::ParseError;
+use aoc_common::parse;
+
+const ENGLISH: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const GERMAN: [&str; 10] = [
+    "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+const SPANISH: [&str; 10] = [
+    "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+
+// The words that count as digits on top of 0-9, e.g. "one" for 1
+#[derive(PartialEq, Debug, Clone)]
+pub struct Vocabulary {
+    words: Vec<(String, usize)>,
+}
+
+impl Vocabulary {
+    pub fn new(words: Vec<(String, usize)>) -> Vocabulary {
+        Vocabulary { words }
+    }
+
+    // one to nine, which is what the puzzle uses
+    pub fn english() -> Vocabulary {
+        Vocabulary::from_digit_words(&ENGLISH[1..], 1)
+    }
+
+    pub fn english_with_zero() -> Vocabulary {
+        Vocabulary::from_digit_words(&ENGLISH, 0)
+    }
+
+    pub fn german() -> Vocabulary {
+        Vocabulary::from_digit_words(&GERMAN, 0)
+    }
+
+    pub fn spanish() -> Vocabulary {
+        Vocabulary::from_digit_words(&SPANISH, 0)
+    }
+
+    // `words` in order, the first standing for `first_digit`
+    fn from_digit_words(words: &[&str], first_digit: usize) -> Vocabulary {
+        Vocabulary::new(
+            words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| (word.to_string(), first_digit + i))
+                .collect(),
+        )
+    }
+
+    // Reads a word list with a word and the digit it stands for on each line,
+    // e.g. "eins 1". Blank lines and lines starting with # are ignored.
+    pub fn from_word_list(word_list: &str) -> Result<Vocabulary, ParseError> {
+        let mut words = vec![];
+
+        for (i, line) in word_list.lines().enumerate() {
+            let line_error = |err: ParseError| err.on_line(i + 1);
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let parts: Vec<&str> = trimmed.split_whitespace().collect();
+            let [word, digit] = parts[..] else {
+                return Err(line_error(ParseError::expected(
+                    "a word and a digit, like \"eins 1\"",
+                    line,
+                    trimmed,
+                )));
+            };
+
+            let digit: usize =
+                parse::number(digit).map_err(|err| line_error(err.within(line, digit)))?;
+            if digit > 9 {
+                return Err(line_error(ParseError::expected(
+                    "a digit from 0 to 9",
+                    line,
+                    parts[1],
+                )));
+            }
+
+            words.push((word.to_string(), digit));
+        }
+
+        Ok(Vocabulary::new(words))
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.words
+            .iter()
+            .map(|(word, digit)| (word.as_str(), *digit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn english_starts_at_one() {
+        let vocabulary = Vocabulary::english();
+        assert_eq!(Some(("one", 1)), vocabulary.words().next());
+        assert_eq!(9, vocabulary.words().count());
+    }
+
+    #[test]
+    fn german() {
+        assert!(Vocabulary::german().words().any(|word| word == ("fünf", 5)));
+    }
+
+    #[test]
+    fn word_list() {
+        let vocabulary = Vocabulary::from_word_list("# Spanish\nuno 1\n\n  dos   2\n").unwrap();
+        assert_eq!(
+            Vocabulary::new(vec![("uno".to_string(), 1), ("dos".to_string(), 2)]),
+            vocabulary
+        );
+    }
+
+    #[test]
+    fn word_list_without_digit() {
+        let error = Vocabulary::from_word_list("uno 1\ndos\n").unwrap_err();
+        assert_eq!(2, error.line);
+        assert_eq!("dos", error.text);
+    }
+
+    #[test]
+    fn word_list_with_number_too_big() {
+        let error = Vocabulary::from_word_list("uno 1\ndiez 10\n").unwrap_err();
+        assert_eq!(
+            "line 2, column 6: expected a digit from 0 to 9, found \"10\"",
+            error.to_string()
+        );
+    }
+}
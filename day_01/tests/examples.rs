@@ -0,0 +1,21 @@
+use aoc_common::solution::Solution;
+use day_01::Day01;
+
+// Part 2 has its own example, since spelled out digits don't count in part 1
+const SAMPLE_INPUT: &str = include_str!("../src/sample_input.txt");
+const SAMPLE_INPUT_2: &str = include_str!("../src/sample_input_2.txt");
+
+#[test]
+fn part_1_example() {
+    assert_eq!(142, Day01::solve(1, SAMPLE_INPUT).unwrap());
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(281, Day01::solve(2, SAMPLE_INPUT_2).unwrap());
+}
+
+#[test]
+fn part_2_reads_part_1_example_the_same() {
+    assert_eq!(142, Day01::solve(2, SAMPLE_INPUT).unwrap());
+}
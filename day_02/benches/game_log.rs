@@ -0,0 +1,45 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_02::cube_set::DuplicateColors;
+use day_02::game::{Game, GAME_DESCRIPTION_PATTERN};
+use day_02::generator::LogGenerator;
+use regex::Regex;
+
+// A log shaped like the puzzle input but a hundred times longer
+fn generated_log() -> String {
+    let generator = LogGenerator {
+        games: 10_000,
+        colors: ["red", "green", "blue", "yellow", "purple"]
+            .map(String::from)
+            .to_vec(),
+        ..LogGenerator::default()
+    };
+    generator.log(2023)
+}
+
+fn parse_log(c: &mut Criterion) {
+    let log = generated_log();
+    let mut group = c.benchmark_group("game log");
+
+    // How every line used to be parsed, for comparison
+    group.bench_function("recompiling the regex per line", |b| {
+        b.iter(|| {
+            black_box(&log)
+                .lines()
+                .map(|line| {
+                    let regex = Regex::new(GAME_DESCRIPTION_PATTERN).expect("valid regex");
+                    black_box(regex);
+                    line.parse::<Game>()
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+    });
+
+    group.bench_function("Game::parse_log", |b| {
+        b.iter(|| Game::parse_log(black_box(&log), DuplicateColors::Reject))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, parse_log);
+criterion_main!(benches);
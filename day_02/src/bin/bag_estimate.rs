@@ -0,0 +1,100 @@
+use aoc_common::diagnostic;
+use aoc_common::input::InputSource;
+use aoc_common::solution::Solution;
+use clap::Parser;
+use day_02::cube_set::{CubeSet, DuplicateColors};
+use day_02::game_log::GameLog;
+use day_02::inference::{self, BagPosterior};
+use day_02::Day02;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(about = "Estimate what was in the bag from the draws in a game log")]
+struct Cli {
+    /// Game log to read draws from (`-` reads stdin, the bundled input is used when omitted)
+    input: Option<String>,
+
+    /// Add up a colour listed more than once in a draw, rather than rejecting the log
+    #[arg(long)]
+    sum_duplicates: bool,
+
+    /// Only use the draws from this game, instead of assuming every game used the same bag
+    #[arg(long)]
+    game: Option<usize>,
+
+    /// The most cubes of any one colour the bag could have held
+    #[arg(long, default_value_t = 40)]
+    max_count: usize,
+
+    /// How much of the posterior the credible intervals hold
+    #[arg(long, default_value_t = 0.9)]
+    mass: f64,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let source = InputSource::from_arg(cli.input.as_deref());
+    let input = match source.read(Day02::INPUT) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Could not read {source}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let duplicates = if cli.sum_duplicates {
+        DuplicateColors::Sum
+    } else {
+        DuplicateColors::Reject
+    };
+    let log = match GameLog::parse(&input, duplicates) {
+        Ok(log) => log,
+        Err(err) => {
+            eprintln!("Invalid game log {source}");
+            eprint!("{}", diagnostic::render(&input, &err));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let draws: Vec<&CubeSet> = log
+        .games()
+        .iter()
+        .filter(|game| cli.game.is_none_or(|id| game.id == id))
+        .flat_map(|game| game.cube_samples())
+        .collect();
+    if draws.is_empty() {
+        eprintln!("No draws to estimate from");
+        return ExitCode::FAILURE;
+    }
+
+    let posterior = BagPosterior::new(&draws, cli.max_count);
+    let maximum_likelihood = posterior.maximum_likelihood();
+
+    println!(
+        "Most likely bag: {maximum_likelihood} (log likelihood {:.3})",
+        inference::log_likelihood(&draws, &maximum_likelihood)
+    );
+    let estimates = posterior.estimates(cli.mass);
+    for estimate in &estimates {
+        println!(
+            "{}: most likely {}, {}% credible interval {} to {}",
+            estimate.color,
+            estimate.maximum_likelihood,
+            cli.mass * 100.0,
+            estimate.lower,
+            estimate.upper
+        );
+    }
+
+    // Larger bags often explain the draws better, in which case the estimates
+    // say more about the prior than the data
+    if estimates
+        .iter()
+        .any(|estimate| estimate.upper >= cli.max_count)
+    {
+        println!("Some estimates reach --max-count, so the bag may well hold more");
+    }
+
+    ExitCode::SUCCESS
+}
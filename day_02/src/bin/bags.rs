@@ -0,0 +1,110 @@
+use aoc_common::diagnostic;
+use aoc_common::input::InputSource;
+use aoc_common::solution::Solution;
+use clap::Parser;
+use day_02::cube_set::{CubeSet, DuplicateColors};
+use day_02::game_log::GameLog;
+use day_02::{puzzle_bag, Day02};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(about = "Try out different bags against a game log")]
+struct Cli {
+    /// Game log to check (`-` reads stdin, the bundled input is used when omitted)
+    input: Option<String>,
+
+    /// Add up a colour listed more than once in a draw, rather than rejecting the log
+    #[arg(long)]
+    sum_duplicates: bool,
+
+    /// The bag to check each game against, written like a draw
+    #[arg(long, default_value_t = puzzle_bag().to_string())]
+    bag: String,
+
+    /// Also show the smallest bag for this many of the easiest games
+    #[arg(long)]
+    easiest: Option<usize>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let source = InputSource::from_arg(cli.input.as_deref());
+    let input = match source.read(Day02::INPUT) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Could not read {source}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let duplicates = if cli.sum_duplicates {
+        DuplicateColors::Sum
+    } else {
+        DuplicateColors::Reject
+    };
+    let log = match GameLog::parse(&input, duplicates) {
+        Ok(log) => log,
+        Err(err) => {
+            eprintln!("Invalid game log {source}");
+            eprint!("{}", diagnostic::render(&input, &err));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let bag = match cli.bag.parse::<CubeSet>() {
+        Ok(bag) => bag,
+        Err(err) => {
+            eprintln!("Invalid bag");
+            eprint!("{}", diagnostic::render(&cli.bag, &err));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    print_games(&log, &bag);
+
+    println!();
+    println!("Smallest bag for every game: {}", log.minimum_bag());
+    if let Some(n) = cli.easiest {
+        println!(
+            "Smallest bag for the {n} easiest games: {}",
+            log.minimum_bag_for_easiest(n)
+        );
+    }
+
+    ExitCode::SUCCESS
+}
+
+// One line per game: whether it's possible with `bag`, and the draws that
+// decide the smallest bag it's possible with. Draws are numbered from 1.
+fn print_games(log: &GameLog, bag: &CubeSet) {
+    for game in log.games() {
+        let status = match game.first_impossible_draw(bag) {
+            Some(draw) => format!("impossible from draw {}", draw + 1),
+            None => "possible".to_string(),
+        };
+        let needs: Vec<String> = game
+            .binding_draws()
+            .iter()
+            .map(|binding| {
+                format!(
+                    "{} {} (draw {})",
+                    binding.count,
+                    binding.color,
+                    binding.draw + 1
+                )
+            })
+            .collect();
+
+        println!("Game {}: {status}, needs {}", game.id, needs.join(", "));
+    }
+
+    let possible: Vec<usize> = log.possible_games(bag).map(|game| game.id).collect();
+    println!();
+    println!(
+        "{} of {} games possible with {bag}, ids summing to {}",
+        possible.len(),
+        log.games().len(),
+        possible.iter().sum::<usize>()
+    );
+}
@@ -0,0 +1,50 @@
+use clap::Parser;
+use day_02::generator::LogGenerator;
+
+#[derive(Parser)]
+#[command(about = "Make up a game log, optionally with some broken lines")]
+struct Cli {
+    /// The same seed always gives the same log
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    #[arg(long, default_value_t = 100)]
+    games: usize,
+
+    /// The most draws in a game, which always has at least one
+    #[arg(long, default_value_t = 6, value_parser = clap::value_parser!(u64).range(1..))]
+    max_draws: u64,
+
+    /// Colours the cubes can be, separated by commas
+    #[arg(long, value_delimiter = ',', default_value = "red,green,blue")]
+    colors: Vec<String>,
+
+    /// The most cubes of one colour in a draw
+    #[arg(long, default_value_t = 20)]
+    max_count: usize,
+
+    /// The chance of each line having a missing count, an unknown colour or a colour repeated in a draw
+    #[arg(long, default_value_t = 0.0, value_parser = malformation_rate)]
+    malformation_rate: f64,
+}
+
+fn malformation_rate(arg: &str) -> Result<f64, String> {
+    match arg.parse::<f64>() {
+        Ok(rate) if (0.0..=1.0).contains(&rate) => Ok(rate),
+        _ => Err("expected a number from 0 to 1".to_string()),
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let generator = LogGenerator {
+        games: cli.games,
+        draws_per_game: 1..=cli.max_draws as usize,
+        colors: cli.colors,
+        max_count: cli.max_count,
+        malformation_rate: cli.malformation_rate,
+    };
+
+    print!("{}", generator.log(cli.seed));
+}
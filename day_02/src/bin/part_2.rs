@@ -1,14 +1,10 @@
-use day_02::game::Game;
+use aoc_common::input;
+use aoc_common::solution::{self, Solution};
+use day_02::Day02;
 
 fn main() {
-    let input = include_str!("../input.txt");
-
-    let result: usize = input
-        .lines()
-        .map(|game_description| Game::from(game_description))
-        .map(|game| game.minimum_starting_cube_set())
-        .map(|cube_set| cube_set.power())
-        .sum();
+    let input = input::load(Day02::INPUT);
+    let result = solution::solve_or_exit::<Day02>(2, &input);
 
     println!("{result}")
 }
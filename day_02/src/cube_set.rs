@@ -1,128 +1,458 @@
-#[derive(PartialEq, Debug)]
+use aoc_common::error::ParseError;
+use aoc_common::parse;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::ops::Add;
+use std::str::FromStr;
+
+// A number of cubes of each colour. Colours without any cubes aren't stored,
+// so two sets with the same cubes are always equal. For the same reason a
+// description can't give a colour no cubes, as that wouldn't be written back.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
 pub struct CubeSet {
-    pub red: usize,
-    pub blue: usize,
-    pub green: usize,
+    counts: BTreeMap<String, usize>,
+}
+
+// What to do when a draw lists the same colour more than once, like
+// "3 red, 2 red"
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum DuplicateColors {
+    Reject,
+    // Count all of them, so "3 red, 2 red" is 5 red
+    Sum,
 }
 
 impl CubeSet {
-    pub fn from(cube_set_description: &str) -> CubeSet {
-        // TODO: Rather than panic, this method should return a Result
-        //       and an error in the case that the input is invalid. Callers
-        //       can decide what they want to do in that case.
-        let mut cube_set = CubeSet {
-            red: 0,
-            blue: 0,
-            green: 0,
-        };
-
-        for color_description in cube_set_description.split(",") {
-            let parts: Vec<&str> = color_description.trim().split(" ").collect();
-
-            match parts[1] {
-                "green" => {
-                    cube_set.green = parts[0].parse().unwrap();
-                }
-                "blue" => {
-                    cube_set.blue = parts[0].parse().unwrap();
-                }
-                "red" => {
-                    cube_set.red = parts[0].parse().unwrap();
-                }
-                _ => {
-                    panic!();
-                }
+    pub fn new() -> CubeSet {
+        CubeSet::default()
+    }
+
+    pub fn from_counts<'a>(counts: impl IntoIterator<Item = (&'a str, usize)>) -> CubeSet {
+        let mut cube_set = CubeSet::new();
+        for (color, count) in counts {
+            cube_set.set(color, count);
+        }
+        cube_set
+    }
+
+    pub fn parse(
+        cube_set_description: &str,
+        duplicates: DuplicateColors,
+    ) -> Result<CubeSet, ParseError> {
+        let mut cube_set = CubeSet::new();
+        let mut seen: Vec<&str> = vec![];
+
+        for color_description in cube_set_description.split(',') {
+            let color_description = color_description.trim();
+            let parts: Vec<&str> = color_description.split_whitespace().collect();
+
+            let [count, color] = parts[..] else {
+                return Err(ParseError::expected(
+                    "a count and a colour, like \"3 blue\"",
+                    cube_set_description,
+                    color_description,
+                ));
             };
+
+            let count_text = count;
+            let count: usize = parse::number(count_text)
+                .map_err(|err| err.within(cube_set_description, count_text))?;
+            if count == 0 {
+                return Err(ParseError::expected(
+                    "a count above 0",
+                    cube_set_description,
+                    count_text,
+                ));
+            }
+
+            if !color.chars().all(char::is_alphabetic) {
+                return Err(ParseError::expected(
+                    "a cube colour, like \"blue\"",
+                    cube_set_description,
+                    color,
+                ));
+            }
+
+            if seen.contains(&color) {
+                if duplicates == DuplicateColors::Reject {
+                    return Err(ParseError::expected(
+                        "each colour only once per draw",
+                        cube_set_description,
+                        color,
+                    ));
+                }
+                cube_set.set(color, cube_set.count(color) + count);
+            } else {
+                seen.push(color);
+                cube_set.set(color, count);
+            }
         }
 
-        cube_set
+        Ok(cube_set)
+    }
+
+    pub fn count(&self, color: &str) -> usize {
+        self.counts.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn set(&mut self, color: &str, count: usize) {
+        if count == 0 {
+            self.counts.remove(color);
+        } else {
+            self.counts.insert(color.to_string(), count);
+        }
+    }
+
+    // Colours with at least one cube, in alphabetical order
+    pub fn colors(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.counts
+            .iter()
+            .map(|(color, count)| (color.as_str(), *count))
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    // The most cubes of each colour in either set, i.e. the smallest set that
+    // contains both
+    pub fn union(&self, other: &CubeSet) -> CubeSet {
+        let mut union = self.clone();
+        for (color, count) in other.colors() {
+            if count > union.count(color) {
+                union.set(color, count);
+            }
+        }
+        union
+    }
+
+    // The cubes left after taking `other` out of this set, or None if there
+    // aren't enough cubes of some colour to take
+    pub fn checked_sub(&self, other: &CubeSet) -> Option<CubeSet> {
+        let mut difference = self.clone();
+        for (color, count) in other.colors() {
+            difference.set(color, self.count(color).checked_sub(count)?);
+        }
+        Some(difference)
     }
 
     pub fn contained_in(&self, other: &CubeSet) -> bool {
-        self.red <= other.red && self.green <= other.green && self.blue <= other.blue
+        self.colors()
+            .all(|(color, count)| count <= other.count(color))
+    }
+
+    // The product of the counts of each of `colors`. A colour that isn't in
+    // the set counts as 0, as it does in the puzzle.
+    pub fn power<'a>(&self, colors: impl IntoIterator<Item = &'a str>) -> usize {
+        colors.into_iter().map(|color| self.count(color)).product()
+    }
+}
+
+// Strict parsing by default, as a repeated colour is more likely a mistake
+// than a variant of the game
+impl FromStr for CubeSet {
+    type Err = ParseError;
+
+    fn from_str(cube_set_description: &str) -> Result<CubeSet, ParseError> {
+        CubeSet::parse(cube_set_description, DuplicateColors::Reject)
+    }
+}
+
+impl TryFrom<&str> for CubeSet {
+    type Error = ParseError;
+
+    fn try_from(cube_set_description: &str) -> Result<CubeSet, ParseError> {
+        cube_set_description.parse()
     }
+}
 
-    pub fn power(&self) -> usize {
-        self.red * self.green * self.blue
+// e.g. "3 blue, 4 red", which parses back into the same set. Colours are
+// written in alphabetical order, and an empty set is written as nothing at all.
+impl Display for CubeSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, (color, count)) in self.colors().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{count} {color}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Add for &CubeSet {
+    type Output = CubeSet;
+
+    fn add(self, other: &CubeSet) -> CubeSet {
+        let mut sum = self.clone();
+        for (color, count) in other.colors() {
+            sum.set(color, sum.count(color) + count);
+        }
+        sum
+    }
+}
+
+impl Add for CubeSet {
+    type Output = CubeSet;
+
+    fn add(self, other: CubeSet) -> CubeSet {
+        &self + &other
     }
 }
 
 #[cfg(test)]
-mod tests {
-    use super::CubeSet;
+pub(crate) mod tests {
+    use super::{CubeSet, DuplicateColors};
+    use crate::PUZZLE_COLORS;
+    use aoc_common::error::ParseErrorKind;
+    use proptest::prelude::*;
+    use std::str::FromStr;
+
+    // Any non-empty set, as an empty one can't be written down
+    pub(crate) fn cube_set() -> impl Strategy<Value = CubeSet> {
+        prop::collection::btree_map("[a-z]{1,8}", 1..100usize, 1..5).prop_map(|counts| {
+            CubeSet::from_counts(counts.iter().map(|(color, count)| (color.as_str(), *count)))
+        })
+    }
+
+    // Any description of distinct colours, including ones with no cubes of a
+    // colour, which don't parse
+    pub(crate) fn cube_set_description() -> impl Strategy<Value = String> {
+        prop::collection::btree_map("[a-z]{1,8}", 0..100usize, 1..5).prop_map(|counts| {
+            counts
+                .iter()
+                .map(|(color, count)| format!("{count} {color}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+    }
 
     #[test]
     fn contained_cube_set() {
-        let smaller_cube_set = CubeSet {
-            red: 1,
-            green: 1,
-            blue: 2,
-        };
-
-        let larger_cube_set = CubeSet {
-            red: 2,
-            green: 2,
-            blue: 2,
-        };
+        let smaller_cube_set = CubeSet::from_counts([("red", 1), ("green", 1), ("blue", 2)]);
+        let larger_cube_set = CubeSet::from_counts([("red", 2), ("green", 2), ("blue", 2)]);
 
         let result = smaller_cube_set.contained_in(&larger_cube_set);
-        assert_eq!(true, result);
+        assert!(result);
     }
 
     #[test]
     fn uncontained_cube_set() {
-        let smaller_cube_set = CubeSet {
-            red: 1,
-            green: 1,
-            blue: 2,
-        };
-
-        let larger_cube_set = CubeSet {
-            red: 2,
-            green: 2,
-            blue: 2,
-        };
+        let smaller_cube_set = CubeSet::from_counts([("red", 1), ("green", 1), ("blue", 2)]);
+        let larger_cube_set = CubeSet::from_counts([("red", 2), ("green", 2), ("blue", 2)]);
 
         let result = larger_cube_set.contained_in(&smaller_cube_set);
-        assert_eq!(false, result);
+        assert!(!result);
+    }
+
+    #[test]
+    fn contained_needs_every_colour() {
+        let with_yellow = CubeSet::from_counts([("red", 1), ("yellow", 1)]);
+        let without_yellow = CubeSet::from_counts([("red", 5), ("blue", 5)]);
+
+        assert!(!with_yellow.contained_in(&without_yellow));
+        assert!(CubeSet::new().contained_in(&without_yellow));
     }
 
     #[test]
     fn from_description_1() {
-        let result = CubeSet::from("3 blue, 4 red");
-        assert_eq!(
-            CubeSet {
-                red: 4,
-                blue: 3,
-                green: 0,
-            },
-            result
-        )
+        let result = CubeSet::from_str("3 blue, 4 red").unwrap();
+        assert_eq!(CubeSet::from_counts([("red", 4), ("blue", 3)]), result)
     }
 
     #[test]
     fn from_description_2() {
-        let result = CubeSet::from("1 red, 2 green, 6 blue");
+        let result = CubeSet::from_str("1 red, 2 green, 6 blue").unwrap();
         assert_eq!(
-            CubeSet {
-                red: 1,
-                blue: 6,
-                green: 2,
-            },
+            CubeSet::from_counts([("red", 1), ("blue", 6), ("green", 2)]),
             result
         )
     }
 
     #[test]
     fn from_description_3() {
-        let result = CubeSet::from("2 green");
+        let result = CubeSet::from_str("2 green").unwrap();
+        assert_eq!(CubeSet::from_counts([("green", 2)]), result)
+    }
+
+    #[test]
+    fn other_colours() {
+        let result = CubeSet::from_str("3 blue, 4 yellow, 1 purple").unwrap();
+        assert_eq!(4, result.count("yellow"));
+        assert_eq!(1, result.count("purple"));
+        assert_eq!(0, result.count("red"));
+    }
+
+    #[test]
+    fn zero_counts_are_not_stored() {
+        let result = CubeSet::from_counts([("blue", 0), ("red", 4)]);
+        assert_eq!(CubeSet::from_counts([("red", 4)]), result);
+        assert_eq!(vec![("red", 4)], result.colors().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn zero_count_rejected() {
+        let error = CubeSet::from_str("3 blue, 0 red").unwrap_err();
         assert_eq!(
-            CubeSet {
-                red: 0,
-                blue: 0,
-                green: 2,
-            },
-            result
-        )
+            ParseErrorKind::Expected("a count above 0".to_string()),
+            error.kind
+        );
+        assert_eq!(9, error.column);
+    }
+
+    #[test]
+    fn invalid_colour() {
+        let error = CubeSet::from_str("3 blue, 4 r3d").unwrap_err();
+        assert_eq!(
+            ParseErrorKind::Expected("a cube colour, like \"blue\"".to_string()),
+            error.kind
+        );
+        assert_eq!(11, error.column);
+        assert_eq!("r3d", error.text);
+    }
+
+    #[test]
+    fn missing_count() {
+        let error = CubeSet::from_str("3 blue, red").unwrap_err();
+        assert_eq!(9, error.column);
+        assert_eq!("red", error.text);
+    }
+
+    #[test]
+    fn invalid_count() {
+        let error = CubeSet::from_str("3 blue, x red").unwrap_err();
+        assert_eq!(ParseErrorKind::InvalidNumber, error.kind);
+        assert_eq!(9, error.column);
+    }
+
+    #[test]
+    fn extra_whitespace() {
+        let result = CubeSet::from_str(" 3  blue ,4\tred").unwrap();
+        assert_eq!(CubeSet::from_counts([("red", 4), ("blue", 3)]), result);
+    }
+
+    #[test]
+    fn duplicate_colour_rejected() {
+        let error = CubeSet::from_str("3 red, 2 blue, 2 red").unwrap_err();
+        assert_eq!(
+            ParseErrorKind::Expected("each colour only once per draw".to_string()),
+            error.kind
+        );
+        assert_eq!(18, error.column);
+        assert_eq!(
+            Err(error),
+            CubeSet::parse("3 red, 2 blue, 2 red", DuplicateColors::Reject)
+        );
+    }
+
+    #[test]
+    fn duplicate_colour_summed() {
+        let result = CubeSet::parse("3 red, 2 blue, 2 red", DuplicateColors::Sum).unwrap();
+        assert_eq!(CubeSet::from_counts([("red", 5), ("blue", 2)]), result);
+    }
+
+    #[test]
+    fn duplicate_zero_count() {
+        // Summing doesn't make a count of 0 any more acceptable
+        for duplicates in [DuplicateColors::Reject, DuplicateColors::Sum] {
+            let error = CubeSet::parse("0 red, 2 red", duplicates).unwrap_err();
+            assert_eq!(
+                ParseErrorKind::Expected("a count above 0".to_string()),
+                error.kind
+            );
+        }
+    }
+
+    #[test]
+    fn union() {
+        let first = CubeSet::from_counts([("red", 4), ("blue", 1)]);
+        let second = CubeSet::from_counts([("red", 2), ("yellow", 3)]);
+
+        assert_eq!(
+            CubeSet::from_counts([("red", 4), ("blue", 1), ("yellow", 3)]),
+            first.union(&second)
+        );
+        assert_eq!(first.union(&second), second.union(&first));
+    }
+
+    #[test]
+    fn add() {
+        let first = CubeSet::from_counts([("red", 4), ("blue", 1)]);
+        let second = CubeSet::from_counts([("red", 2), ("yellow", 3)]);
+
+        assert_eq!(
+            CubeSet::from_counts([("red", 6), ("blue", 1), ("yellow", 3)]),
+            first + second
+        );
+    }
+
+    #[test]
+    fn checked_sub() {
+        let bag = CubeSet::from_counts([("red", 4), ("blue", 1)]);
+
+        assert_eq!(
+            Some(CubeSet::from_counts([("red", 1)])),
+            bag.checked_sub(&CubeSet::from_counts([("red", 3), ("blue", 1)]))
+        );
+        assert_eq!(None, bag.checked_sub(&CubeSet::from_counts([("red", 5)])));
+        assert_eq!(
+            None,
+            bag.checked_sub(&CubeSet::from_counts([("purple", 1)]))
+        );
+    }
+
+    #[test]
+    fn total() {
+        let cube_set = CubeSet::from_counts([("red", 2), ("blue", 3), ("purple", 4)]);
+        assert_eq!(9, cube_set.total());
+        assert_eq!(0, CubeSet::new().total());
+    }
+
+    #[test]
+    fn power() {
+        let cube_set = CubeSet::from_counts([("red", 2), ("blue", 3), ("purple", 4)]);
+        assert_eq!(24, cube_set.power(["red", "blue", "purple"]));
+        assert_eq!(6, cube_set.power(["red", "blue"]));
+    }
+
+    #[test]
+    fn power_without_blue() {
+        let cube_set = CubeSet::from_counts([("red", 2), ("green", 3)]);
+        assert_eq!(0, cube_set.power(PUZZLE_COLORS));
+    }
+
+    #[test]
+    fn try_from() {
+        assert_eq!(
+            CubeSet::from_str("3 blue, 4 red"),
+            CubeSet::try_from("3 blue, 4 red")
+        );
+        assert!(CubeSet::try_from("3 blue, 4").is_err());
+    }
+
+    #[test]
+    fn display() {
+        let cube_set = CubeSet::from_counts([("red", 4), ("blue", 3), ("green", 0)]);
+        assert_eq!("3 blue, 4 red", cube_set.to_string());
+        assert_eq!("", CubeSet::new().to_string());
+    }
+
+    proptest! {
+        #[test]
+        fn display_round_trips(cube_set in cube_set()) {
+            prop_assert_eq!(Ok(cube_set.clone()), cube_set.to_string().parse());
+        }
+
+        #[test]
+        fn parsed_descriptions_round_trip(description in cube_set_description()) {
+            match description.parse::<CubeSet>() {
+                Ok(cube_set) => prop_assert_eq!(Ok(cube_set.clone()), cube_set.to_string().parse()),
+                Err(_) => prop_assert!(description.split(", ").any(|color| color.starts_with("0 "))),
+            }
+        }
     }
 }
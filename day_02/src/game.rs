@@ -1,7 +1,19 @@
-use crate::cube_set::CubeSet;
+use crate::cube_set::{CubeSet, DuplicateColors};
+use aoc_common::error::ParseError;
+use aoc_common::parse;
 use regex::Regex;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::OnceLock;
 
-const GAME_DESCRIPTION_PATTERN: &str = r"^Game ([0-9]+): (.+)$";
+pub const GAME_DESCRIPTION_PATTERN: &str = r"^Game ([0-9]+): (.+)$";
+
+// Compiled the first time a game is parsed and shared from then on, as
+// compiling it takes far longer than matching a line
+fn game_regex() -> &'static Regex {
+    static GAME_REGEX: OnceLock<Regex> = OnceLock::new();
+    GAME_REGEX.get_or_init(|| Regex::new(GAME_DESCRIPTION_PATTERN).expect("valid regex"))
+}
 
 #[derive(PartialEq, Debug)]
 pub struct Game {
@@ -9,151 +21,173 @@ pub struct Game {
     cube_samples: Vec<CubeSet>,
 }
 
+// The draw that decides how many cubes of one colour a game needs
+#[derive(PartialEq, Debug)]
+pub struct BindingDraw {
+    pub color: String,
+    pub count: usize,
+    // Which of the game's draws it was, counting from 0
+    pub draw: usize,
+}
+
 impl Game {
-    pub fn from(game_description: &str) -> Game {
-        let game_regex = Regex::new(GAME_DESCRIPTION_PATTERN).unwrap();
-        let (_, [game_id, cube_set_descriptions]) = game_regex
-            .captures(game_description)
-            .unwrap()
-            .extract::<2>();
-
-        Game {
-            id: game_id.parse().expect("This should be a number string, it's from a regex matching digits!"),
+    pub fn parse(game_description: &str, duplicates: DuplicateColors) -> Result<Game, ParseError> {
+        let [game_id, cube_set_descriptions] = parse::captures(game_regex(), game_description)?;
+
+        Ok(Game {
+            id: parse::number(game_id).map_err(|err| err.within(game_description, game_id))?,
             cube_samples: cube_set_descriptions
-                .split("; ")
-                .map(|cube_set_description| CubeSet::from(cube_set_description))
-                .collect(),
-        }
+                .split(';')
+                .map(|cube_set_description| {
+                    let cube_set_description = cube_set_description.trim();
+                    CubeSet::parse(cube_set_description, duplicates)
+                        .map_err(|err| err.within(game_description, cube_set_description))
+                })
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    // Parses a whole game log, one game per line
+    pub fn parse_log(log: &str, duplicates: DuplicateColors) -> Result<Vec<Game>, ParseError> {
+        log.lines()
+            .enumerate()
+            .map(|(i, line)| Game::parse(line, duplicates).map_err(|err| err.on_line(i + 1)))
+            .collect()
+    }
+
+    pub fn cube_samples(&self) -> &[CubeSet] {
+        &self.cube_samples
     }
 
     pub fn is_possible_for_cube_set(&self, total_cube_set: &CubeSet) -> bool {
         self.cube_samples
             .iter()
-            .all(|cube_sample| cube_sample.contained_in(&total_cube_set))
+            .all(|cube_sample| cube_sample.contained_in(total_cube_set))
     }
 
+    // The fewest cubes of each colour the bag could have held
     pub fn minimum_starting_cube_set(&self) -> CubeSet {
-        CubeSet {
-            red: self.max_red(),
-            blue: self.max_blue(),
-            green: self.max_green(),
-        }
+        self.cube_samples
+            .iter()
+            .fold(CubeSet::new(), |minimum, cube_sample| {
+                minimum.union(cube_sample)
+            })
     }
 
-    fn max_red(&self) -> usize {
-        self.cube_samples.iter().fold(0, |curr_largest, cube_set| {
-            if cube_set.red > curr_largest {
-                cube_set.red
-            } else {
-                curr_largest
-            }
-        })
+    // For each colour, in alphabetical order, the first draw with the most
+    // cubes of that colour
+    pub fn binding_draws(&self) -> Vec<BindingDraw> {
+        self.minimum_starting_cube_set()
+            .colors()
+            .map(|(color, count)| BindingDraw {
+                color: color.to_string(),
+                count,
+                draw: self
+                    .cube_samples
+                    .iter()
+                    .position(|cube_sample| cube_sample.count(color) == count)
+                    .expect("the most cubes were seen in some draw"),
+            })
+            .collect()
     }
 
-    fn max_blue(&self) -> usize {
-        self.cube_samples.iter().fold(0, |curr_largest, cube_set| {
-            if cube_set.blue > curr_largest {
-                cube_set.blue
-            } else {
-                curr_largest
-            }
-        })
+    // The first draw that couldn't have come out of `total_cube_set`
+    pub fn first_impossible_draw(&self, total_cube_set: &CubeSet) -> Option<usize> {
+        self.cube_samples
+            .iter()
+            .position(|cube_sample| !cube_sample.contained_in(total_cube_set))
     }
+}
+
+// Rejects a colour repeated within a draw, like CubeSet's FromStr
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(game_description: &str) -> Result<Game, ParseError> {
+        Game::parse(game_description, DuplicateColors::Reject)
+    }
+}
+
+impl TryFrom<&str> for Game {
+    type Error = ParseError;
+
+    fn try_from(game_description: &str) -> Result<Game, ParseError> {
+        game_description.parse()
+    }
+}
 
-    fn max_green(&self) -> usize {
-        self.cube_samples.iter().fold(0, |curr_largest, cube_set| {
-            if cube_set.green > curr_largest {
-                cube_set.green
-            } else {
-                curr_largest
+// The same form the puzzle input uses, e.g. "Game 1: 3 blue, 4 red; 2 green"
+impl Display for Game {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
+        for (i, cube_sample) in self.cube_samples.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
             }
-        })
+            write!(f, "{cube_sample}")?;
+        }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::cube_set::CubeSet;
-    use crate::game::Game;
+    use crate::cube_set::tests::{cube_set, cube_set_description};
+    use crate::cube_set::{CubeSet, DuplicateColors};
+    use crate::game::{BindingDraw, Game, GAME_DESCRIPTION_PATTERN};
+    use crate::PUZZLE_COLORS;
+    use aoc_common::error::ParseErrorKind;
+    use proptest::prelude::*;
+    use std::str::FromStr;
+
+    fn game() -> impl Strategy<Value = Game> {
+        (0..1000usize, prop::collection::vec(cube_set(), 1..6))
+            .prop_map(|(id, cube_samples)| Game { id, cube_samples })
+    }
 
     #[test]
     fn possible_game() {
-        let total_cube_set = CubeSet {
-            red: 4,
-            blue: 3,
-            green: 6,
-        };
+        let total_cube_set = CubeSet::from_counts([("red", 4), ("blue", 3), ("green", 6)]);
 
-        let game_cube_sample_1 = CubeSet {
-            red: 2,
-            blue: 1,
-            green: 0,
-        };
+        let game_cube_sample_1 = CubeSet::from_counts([("red", 2), ("blue", 1)]);
 
-        let game_cube_sample_2 = CubeSet {
-            red: 4,
-            blue: 0,
-            green: 6,
-        };
+        let game_cube_sample_2 = CubeSet::from_counts([("red", 4), ("green", 6)]);
 
         let game = Game {
             id: 0,
             cube_samples: vec![game_cube_sample_1, game_cube_sample_2],
         };
 
-        assert_eq!(true, game.is_possible_for_cube_set(&total_cube_set))
+        assert!(game.is_possible_for_cube_set(&total_cube_set))
     }
 
     #[test]
     fn impossible_game() {
-        let total_cube_set = CubeSet {
-            red: 1,
-            blue: 1,
-            green: 1,
-        };
+        let total_cube_set = CubeSet::from_counts([("red", 1), ("blue", 1), ("green", 1)]);
 
-        let game_cube_sample_1 = CubeSet {
-            red: 2,
-            blue: 1,
-            green: 0,
-        };
+        let game_cube_sample_1 = CubeSet::from_counts([("red", 2), ("blue", 1)]);
 
-        let game_cube_sample_2 = CubeSet {
-            red: 4,
-            blue: 0,
-            green: 6,
-        };
+        let game_cube_sample_2 = CubeSet::from_counts([("red", 4), ("green", 6)]);
 
         let game = Game {
             id: 0,
             cube_samples: vec![game_cube_sample_1, game_cube_sample_2],
         };
 
-        assert_eq!(false, game.is_possible_for_cube_set(&total_cube_set))
+        assert!(!game.is_possible_for_cube_set(&total_cube_set))
     }
 
     #[test]
     fn game_description_1() {
-        let game = Game::from("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green");
+        let game =
+            Game::from_str("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
         assert_eq!(
             Game {
                 id: 1,
                 cube_samples: vec![
-                    CubeSet {
-                        blue: 3,
-                        red: 4,
-                        green: 0,
-                    },
-                    CubeSet {
-                        blue: 6,
-                        red: 1,
-                        green: 2,
-                    },
-                    CubeSet {
-                        blue: 0,
-                        red: 0,
-                        green: 2,
-                    }
+                    CubeSet::from_counts([("blue", 3), ("red", 4)]),
+                    CubeSet::from_counts([("blue", 6), ("red", 1), ("green", 2)]),
+                    CubeSet::from_counts([("green", 2)])
                 ]
             },
             game
@@ -162,27 +196,17 @@ mod tests {
 
     #[test]
     fn game_description_2() {
-        let game = Game::from("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue");
+        let game =
+            Game::from_str("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue")
+                .unwrap();
 
         assert_eq!(
             Game {
                 id: 2,
                 cube_samples: vec![
-                    CubeSet {
-                        blue: 1,
-                        red: 0,
-                        green: 2,
-                    },
-                    CubeSet {
-                        blue: 4,
-                        red: 1,
-                        green: 3,
-                    },
-                    CubeSet {
-                        blue: 1,
-                        red: 0,
-                        green: 1,
-                    }
+                    CubeSet::from_counts([("blue", 1), ("green", 2)]),
+                    CubeSet::from_counts([("blue", 4), ("red", 1), ("green", 3)]),
+                    CubeSet::from_counts([("blue", 1), ("green", 1)])
                 ]
             },
             game
@@ -191,28 +215,18 @@ mod tests {
 
     #[test]
     fn game_description_3() {
-        let game =
-            Game::from("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red");
+        let game = Game::from_str(
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+        )
+        .unwrap();
 
         assert_eq!(
             Game {
                 id: 3,
                 cube_samples: vec![
-                    CubeSet {
-                        blue: 6,
-                        red: 20,
-                        green: 8,
-                    },
-                    CubeSet {
-                        blue: 5,
-                        red: 4,
-                        green: 13,
-                    },
-                    CubeSet {
-                        blue: 0,
-                        red: 1,
-                        green: 5,
-                    }
+                    CubeSet::from_counts([("blue", 6), ("red", 20), ("green", 8)]),
+                    CubeSet::from_counts([("blue", 5), ("red", 4), ("green", 13)]),
+                    CubeSet::from_counts([("red", 1), ("green", 5)])
                 ]
             },
             game
@@ -221,28 +235,18 @@ mod tests {
 
     #[test]
     fn game_description_4() {
-        let game =
-            Game::from("Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red");
+        let game = Game::from_str(
+            "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+        )
+        .unwrap();
 
         assert_eq!(
             Game {
                 id: 4,
                 cube_samples: vec![
-                    CubeSet {
-                        blue: 6,
-                        red: 3,
-                        green: 1,
-                    },
-                    CubeSet {
-                        blue: 0,
-                        red: 6,
-                        green: 3,
-                    },
-                    CubeSet {
-                        blue: 15,
-                        red: 14,
-                        green: 3,
-                    }
+                    CubeSet::from_counts([("blue", 6), ("red", 3), ("green", 1)]),
+                    CubeSet::from_counts([("red", 6), ("green", 3)]),
+                    CubeSet::from_counts([("blue", 15), ("red", 14), ("green", 3)])
                 ]
             },
             game
@@ -251,22 +255,15 @@ mod tests {
 
     #[test]
     fn game_description_5() {
-        let game = Game::from("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green");
+        let game =
+            Game::from_str("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green").unwrap();
 
         assert_eq!(
             Game {
                 id: 5,
                 cube_samples: vec![
-                    CubeSet {
-                        blue: 1,
-                        red: 6,
-                        green: 3,
-                    },
-                    CubeSet {
-                        blue: 2,
-                        red: 1,
-                        green: 2,
-                    },
+                    CubeSet::from_counts([("blue", 1), ("red", 6), ("green", 3)]),
+                    CubeSet::from_counts([("blue", 2), ("red", 1), ("green", 2)]),
                 ]
             },
             game
@@ -275,73 +272,233 @@ mod tests {
 
     #[test]
     fn minimum_cube_set_for_game_1() {
-        let game = Game::from("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green");
+        let game =
+            Game::from_str("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
         assert_eq!(
-            CubeSet {
-                red: 4,
-                green: 2,
-                blue: 6
-            },
+            CubeSet::from_counts([("red", 4), ("green", 2), ("blue", 6)]),
             game.minimum_starting_cube_set()
         );
-        assert_eq!(48, game.minimum_starting_cube_set().power());
+        assert_eq!(48, game.minimum_starting_cube_set().power(PUZZLE_COLORS));
     }
 
     #[test]
     fn minimum_cube_set_for_game_2() {
-        let game = Game::from("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue");
+        let game =
+            Game::from_str("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue")
+                .unwrap();
         assert_eq!(
-            CubeSet {
-                red: 1,
-                green: 3,
-                blue: 4
-            },
+            CubeSet::from_counts([("red", 1), ("green", 3), ("blue", 4)]),
             game.minimum_starting_cube_set()
         );
-        assert_eq!(12, game.minimum_starting_cube_set().power());
+        assert_eq!(12, game.minimum_starting_cube_set().power(PUZZLE_COLORS));
     }
 
     #[test]
     fn minimum_cube_set_for_game_3() {
-        let game =
-            Game::from("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red");
+        let game = Game::from_str(
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+        )
+        .unwrap();
         assert_eq!(
-            CubeSet {
-                red: 20,
-                green: 13,
-                blue: 6
-            },
+            CubeSet::from_counts([("red", 20), ("green", 13), ("blue", 6)]),
             game.minimum_starting_cube_set()
         );
-        assert_eq!(1560, game.minimum_starting_cube_set().power());
+        assert_eq!(1560, game.minimum_starting_cube_set().power(PUZZLE_COLORS));
     }
 
     #[test]
     fn minimum_cube_set_for_game_4() {
-        let game =
-            Game::from("Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red");
+        let game = Game::from_str(
+            "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+        )
+        .unwrap();
         assert_eq!(
-            CubeSet {
-                red: 14,
-                green: 3,
-                blue: 15
-            },
+            CubeSet::from_counts([("red", 14), ("green", 3), ("blue", 15)]),
             game.minimum_starting_cube_set()
         );
-        assert_eq!(630, game.minimum_starting_cube_set().power());
+        assert_eq!(630, game.minimum_starting_cube_set().power(PUZZLE_COLORS));
     }
 
     #[test]
     fn minimum_cube_set_for_game_5() {
-        let game = Game::from("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green");
+        let game =
+            Game::from_str("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green").unwrap();
+        assert_eq!(
+            CubeSet::from_counts([("red", 6), ("green", 3), ("blue", 2)]),
+            game.minimum_starting_cube_set()
+        );
+        assert_eq!(36, game.minimum_starting_cube_set().power(PUZZLE_COLORS));
+    }
+
+    #[test]
+    fn game_description_with_other_colours() {
+        let game = Game::from_str("Game 6: 1 red; 2 purple, 1 blue").unwrap();
         assert_eq!(
-            CubeSet {
-                red: 6,
-                green: 3,
-                blue: 2
+            CubeSet::from_counts([("red", 1), ("purple", 2), ("blue", 1)]),
+            game.minimum_starting_cube_set()
+        );
+        assert!(!game.is_possible_for_cube_set(&CubeSet::from_counts([
+            ("red", 12),
+            ("green", 13),
+            ("blue", 14)
+        ])));
+    }
+
+    #[test]
+    fn game_description_with_invalid_colour() {
+        let error = Game::from_str("Game 6: 1 red; 2 purple!, 1 blue").unwrap_err();
+        assert_eq!(18, error.column);
+        assert_eq!("purple!", error.text);
+    }
+
+    #[test]
+    fn malformed_game_description() {
+        let error = Game::from_str("Gmae 7: 1 red").unwrap_err();
+        assert_eq!(
+            ParseErrorKind::NoMatch {
+                pattern: GAME_DESCRIPTION_PATTERN.to_string()
             },
+            error.kind
+        );
+    }
+
+    #[test]
+    fn display() {
+        let description = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let game = Game::from_str(description).unwrap();
+        assert_eq!(
+            "Game 1: 3 blue, 4 red; 6 blue, 2 green, 1 red; 2 green",
+            game.to_string()
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        assert!(Game::try_from("Game 1: 3 blue").is_ok());
+        assert!(Game::try_from("Game 1:").is_err());
+    }
+
+    // A draw of nothing but zeros would be written as an empty draw, which
+    // doesn't parse
+    #[test]
+    fn zero_count_draws() {
+        for (description, column) in [("Game 1: 0 red", 9), ("Game 1: 3 blue; 0 red", 17)] {
+            let error = Game::from_str(description).unwrap_err();
+            assert_eq!(
+                ParseErrorKind::Expected("a count above 0".to_string()),
+                error.kind
+            );
+            assert_eq!(column, error.column);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn display_round_trips(game in game()) {
+            let parsed = game.to_string().parse::<Game>();
+            prop_assert_eq!(Ok(game), parsed);
+        }
+
+        #[test]
+        fn parsed_descriptions_round_trip(
+            id in 0..1000usize,
+            draws in prop::collection::vec(cube_set_description(), 1..6),
+        ) {
+            let description = format!("Game {id}: {}", draws.join("; "));
+            if let Ok(game) = description.parse::<Game>() {
+                let displayed = game.to_string();
+                prop_assert_eq!(Ok(game), displayed.parse::<Game>());
+            }
+        }
+    }
+
+    #[test]
+    fn parse_log() {
+        let games = Game::parse_log(
+            "Game 1: 3 blue\nGame 2: 1 red; 2 green\n",
+            DuplicateColors::Reject,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![1, 2],
+            games.iter().map(|game| game.id).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn parse_log_error_line() {
+        let error = Game::parse_log(
+            "Game 1: 3 blue\nGame 2: 1 red; green\n",
+            DuplicateColors::Reject,
+        )
+        .unwrap_err();
+        assert_eq!(2, error.line);
+        assert_eq!(16, error.column);
+    }
+
+    #[test]
+    fn binding_draws() {
+        let game =
+            Game::from_str("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+        assert_eq!(
+            vec![
+                BindingDraw {
+                    color: "blue".to_string(),
+                    count: 6,
+                    draw: 1
+                },
+                BindingDraw {
+                    color: "green".to_string(),
+                    count: 2,
+                    draw: 1
+                },
+                BindingDraw {
+                    color: "red".to_string(),
+                    count: 4,
+                    draw: 0
+                },
+            ],
+            game.binding_draws()
+        );
+    }
+
+    #[test]
+    fn first_impossible_draw() {
+        let game =
+            Game::from_str("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+        assert_eq!(
+            Some(1),
+            game.first_impossible_draw(&CubeSet::from_counts([
+                ("blue", 5),
+                ("red", 5),
+                ("green", 5)
+            ]))
+        );
+        assert_eq!(
+            None,
+            game.first_impossible_draw(&game.minimum_starting_cube_set())
+        );
+    }
+
+    #[test]
+    fn draws_with_extra_whitespace() {
+        let game = Game::from_str("Game 8: 1 red;2 green ;  3  blue").unwrap();
+        assert_eq!(3, game.cube_samples().len());
+        assert_eq!("Game 8: 1 red; 2 green; 3 blue", game.to_string());
+    }
+
+    #[test]
+    fn duplicate_colours() {
+        let description = "Game 9: 1 red; 2 green, 3 blue, 1 green";
+
+        let error = Game::parse(description, DuplicateColors::Reject).unwrap_err();
+        assert_eq!(35, error.column);
+        assert_eq!(Err(error), Game::from_str(description));
+
+        let game = Game::parse(description, DuplicateColors::Sum).unwrap();
+        assert_eq!(
+            CubeSet::from_counts([("red", 1), ("green", 3), ("blue", 3)]),
             game.minimum_starting_cube_set()
         );
-        assert_eq!(36, game.minimum_starting_cube_set().power());
     }
 }
@@ -0,0 +1,113 @@
+use crate::cube_set::{CubeSet, DuplicateColors};
+use crate::game::Game;
+use aoc_common::error::ParseError;
+use std::str::FromStr;
+
+// Every game played, for asking which bags they could have been played with
+#[derive(PartialEq, Debug)]
+pub struct GameLog {
+    games: Vec<Game>,
+}
+
+impl GameLog {
+    pub fn new(games: Vec<Game>) -> GameLog {
+        GameLog { games }
+    }
+
+    pub fn parse(log: &str, duplicates: DuplicateColors) -> Result<GameLog, ParseError> {
+        Ok(GameLog::new(Game::parse_log(log, duplicates)?))
+    }
+
+    pub fn games(&self) -> &[Game] {
+        &self.games
+    }
+
+    pub fn possible_games<'a>(&'a self, bag: &'a CubeSet) -> impl Iterator<Item = &'a Game> {
+        self.games
+            .iter()
+            .filter(|game| game.is_possible_for_cube_set(bag))
+    }
+
+    // The smallest bag every game in the log could have been played with
+    pub fn minimum_bag(&self) -> CubeSet {
+        minimum_bag_for(&self.games)
+    }
+
+    // The `n` games needing the fewest cubes in total, fewest first. Games
+    // needing the same number come in the order they were played.
+    pub fn easiest_games(&self, n: usize) -> Vec<&Game> {
+        let mut games: Vec<&Game> = self.games.iter().collect();
+        games.sort_by_key(|game| game.minimum_starting_cube_set().total());
+        games.truncate(n);
+        games
+    }
+
+    // The smallest bag the `n` easiest games could all have been played with
+    pub fn minimum_bag_for_easiest(&self, n: usize) -> CubeSet {
+        minimum_bag_for(self.easiest_games(n))
+    }
+}
+
+pub fn minimum_bag_for<'a>(games: impl IntoIterator<Item = &'a Game>) -> CubeSet {
+    games.into_iter().fold(CubeSet::new(), |bag, game| {
+        bag.union(&game.minimum_starting_cube_set())
+    })
+}
+
+impl FromStr for GameLog {
+    type Err = ParseError;
+
+    fn from_str(log: &str) -> Result<GameLog, ParseError> {
+        GameLog::parse(log, DuplicateColors::Reject)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = include_str!("sample_input.txt");
+
+    fn ids<'a>(games: impl IntoIterator<Item = &'a Game>) -> Vec<usize> {
+        games.into_iter().map(|game| game.id).collect()
+    }
+
+    #[test]
+    fn possible_games() {
+        let log = GameLog::from_str(SAMPLE_INPUT).unwrap();
+        let bag = CubeSet::from_counts([("red", 12), ("green", 13), ("blue", 14)]);
+        assert_eq!(vec![1, 2, 5], ids(log.possible_games(&bag)));
+    }
+
+    #[test]
+    fn minimum_bag() {
+        let log = GameLog::from_str(SAMPLE_INPUT).unwrap();
+        let bag = log.minimum_bag();
+
+        assert_eq!(
+            CubeSet::from_counts([("red", 20), ("green", 13), ("blue", 15)]),
+            bag
+        );
+        assert_eq!(5, log.possible_games(&bag).count());
+    }
+
+    #[test]
+    fn easiest_games() {
+        let log = GameLog::from_str(SAMPLE_INPUT).unwrap();
+        assert_eq!(vec![2, 5, 1], ids(log.easiest_games(3)));
+        assert_eq!(5, log.easiest_games(10).len());
+    }
+
+    #[test]
+    fn minimum_bag_for_easiest() {
+        let log = GameLog::from_str(SAMPLE_INPUT).unwrap();
+        let bag = log.minimum_bag_for_easiest(2);
+
+        assert_eq!(
+            CubeSet::from_counts([("red", 6), ("green", 3), ("blue", 4)]),
+            bag
+        );
+        assert_eq!(vec![2, 5], ids(log.possible_games(&bag)));
+        assert_eq!(CubeSet::new(), log.minimum_bag_for_easiest(0));
+    }
+}
@@ -0,0 +1,233 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::ops::RangeInclusive;
+
+// Colours to use when a game needs one that isn't in the generator's set
+const OTHER_COLORS: [&str; 5] = ["yellow", "purple", "orange", "mauve", "teal"];
+
+// Ways a generated line can be broken on purpose
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Malformation {
+    // e.g. "red" rather than "3 red"
+    MissingCount,
+    // A colour that isn't in the generator's set. Any colour parses, so it's
+    // up to the caller to notice.
+    UnknownColor,
+    // The same colour twice in one draw, e.g. "3 red, 2 red". It's up to
+    // the caller whether that's an error.
+    DuplicateColor,
+}
+
+impl Malformation {
+    const ALL: [Malformation; 3] = [
+        Malformation::MissingCount,
+        Malformation::UnknownColor,
+        Malformation::DuplicateColor,
+    ];
+}
+
+// A colour in a draw, with its count unless that's been left out
+type Cubes = (Option<usize>, String);
+
+#[derive(PartialEq, Debug)]
+pub struct GeneratedLine {
+    pub text: String,
+    pub malformation: Option<Malformation>,
+}
+
+// Makes up game logs in the puzzle's format. The same seed always gives the
+// same log.
+#[derive(Debug, Clone)]
+pub struct LogGenerator {
+    pub games: usize,
+    // Every game has at least one draw, whatever this says
+    pub draws_per_game: RangeInclusive<usize>,
+    // There has to be at least one
+    pub colors: Vec<String>,
+    // Each colour in a draw has between 1 and this many cubes
+    pub max_count: usize,
+    // The chance of each line being malformed, from 0 to 1
+    pub malformation_rate: f64,
+}
+
+impl Default for LogGenerator {
+    // About the size and shape of the puzzle input
+    fn default() -> LogGenerator {
+        LogGenerator {
+            games: 100,
+            draws_per_game: 1..=6,
+            colors: vec!["red".to_string(), "green".to_string(), "blue".to_string()],
+            max_count: 20,
+            malformation_rate: 0.0,
+        }
+    }
+}
+
+impl LogGenerator {
+    pub fn log(&self, seed: u64) -> String {
+        self.lines(seed)
+            .iter()
+            .map(|line| format!("{}\n", line.text))
+            .collect()
+    }
+
+    pub fn lines(&self, seed: u64) -> Vec<GeneratedLine> {
+        assert!(!self.colors.is_empty(), "a log needs at least one colour");
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        (1..=self.games)
+            .map(|id| {
+                let draw_count = if self.draws_per_game.is_empty() {
+                    1
+                } else {
+                    rng.gen_range(self.draws_per_game.clone()).max(1)
+                };
+                let mut draws: Vec<Vec<Cubes>> =
+                    (0..draw_count).map(|_| self.draw(&mut rng)).collect();
+
+                let malformation = if rng.gen_bool(self.malformation_rate) {
+                    let malformation = *Malformation::ALL.choose(&mut rng).expect("not empty");
+                    self.malform(&mut rng, &mut draws, malformation);
+                    Some(malformation)
+                } else {
+                    None
+                };
+
+                let draws: Vec<String> = draws
+                    .iter()
+                    .map(|draw| {
+                        let cubes: Vec<String> = draw
+                            .iter()
+                            .map(|(count, color)| match count {
+                                Some(count) => format!("{count} {color}"),
+                                None => color.clone(),
+                            })
+                            .collect();
+                        cubes.join(", ")
+                    })
+                    .collect();
+
+                GeneratedLine {
+                    text: format!("Game {id}: {}", draws.join("; ")),
+                    malformation,
+                }
+            })
+            .collect()
+    }
+
+    // A count for some of the colours, in any order
+    fn draw(&self, rng: &mut StdRng) -> Vec<Cubes> {
+        let mut colors: Vec<&String> = self.colors.iter().collect();
+        colors.shuffle(rng);
+        colors.truncate(rng.gen_range(1..=colors.len()));
+
+        colors
+            .into_iter()
+            .map(|color| (Some(self.count(rng)), color.clone()))
+            .collect()
+    }
+
+    fn count(&self, rng: &mut StdRng) -> usize {
+        rng.gen_range(1..=self.max_count.max(1))
+    }
+
+    fn malform(&self, rng: &mut StdRng, draws: &mut [Vec<Cubes>], malformation: Malformation) {
+        let draw = draws.choose_mut(rng).expect("games are never empty");
+
+        match malformation {
+            Malformation::MissingCount => {
+                let cube = draw.choose_mut(rng).expect("draws are never empty");
+                cube.0 = None;
+            }
+            Malformation::UnknownColor => {
+                let unknown = OTHER_COLORS
+                    .iter()
+                    .find(|color| !self.colors.iter().any(|known| known == *color))
+                    .unwrap_or(&"unknown");
+                draw.push((Some(self.count(rng)), unknown.to_string()));
+            }
+            Malformation::DuplicateColor => {
+                let color = draw.choose(rng).expect("draws are never empty").1.clone();
+                draw.push((Some(self.count(rng)), color));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cube_set::{CubeSet, DuplicateColors};
+    use crate::game::Game;
+    use std::collections::HashSet;
+
+    #[test]
+    fn same_seed_same_log() {
+        let generator = LogGenerator::default();
+        assert_eq!(generator.log(7), generator.log(7));
+        assert_ne!(generator.log(7), generator.log(8));
+    }
+
+    #[test]
+    fn valid_log() {
+        let generator = LogGenerator {
+            games: 50,
+            draws_per_game: 2..=3,
+            colors: vec!["yellow".to_string(), "purple".to_string()],
+            ..LogGenerator::default()
+        };
+        let games = Game::parse_log(&generator.log(1), DuplicateColors::Reject).unwrap();
+
+        assert_eq!(50, games.len());
+        for game in games {
+            assert!((2..=3).contains(&game.cube_samples().len()));
+            let allowed = CubeSet::from_counts([("yellow", 20), ("purple", 20)]);
+            assert!(game.is_possible_for_cube_set(&allowed));
+        }
+    }
+
+    #[test]
+    fn at_least_one_draw() {
+        for draws_per_game in [0..=0, RangeInclusive::new(3, 1)] {
+            let generator = LogGenerator {
+                draws_per_game,
+                ..LogGenerator::default()
+            };
+            let games = Game::parse_log(&generator.log(3), DuplicateColors::Reject).unwrap();
+            assert!(games.iter().all(|game| game.cube_samples().len() == 1));
+        }
+    }
+
+    #[test]
+    fn every_malformation() {
+        let generator = LogGenerator {
+            malformation_rate: 1.0,
+            ..LogGenerator::default()
+        };
+        let lines = generator.lines(2);
+
+        let malformations: HashSet<_> = lines.iter().map(|line| line.malformation).collect();
+        assert_eq!(HashSet::from(Malformation::ALL.map(Some)), malformations);
+
+        for line in lines {
+            let result = line.text.parse::<Game>();
+            match line.malformation {
+                Some(Malformation::MissingCount) => assert!(result.is_err(), "{}", line.text),
+                Some(Malformation::UnknownColor) => {
+                    let game = result.unwrap();
+                    assert!(!game.is_possible_for_cube_set(&CubeSet::from_counts([
+                        ("red", 20),
+                        ("green", 20),
+                        ("blue", 20)
+                    ])));
+                }
+                Some(Malformation::DuplicateColor) => {
+                    assert!(result.is_err(), "{}", line.text);
+                    assert!(Game::parse(&line.text, DuplicateColors::Sum).is_ok());
+                }
+                None => assert!(result.is_ok()),
+            }
+        }
+    }
+}
@@ -0,0 +1,342 @@
+use crate::cube_set::CubeSet;
+
+// What the draws say about how many cubes of each colour were in the bag.
+// Each draw is treated as cubes taken out together, without replacement, and
+// put back before the next draw. Every bag with between the most cubes seen
+// in a draw and `max_count` cubes of each colour is considered equally likely
+// before looking at the draws. Colours that never come up are assumed not to
+// be in the bag at all.
+pub struct BagPosterior {
+    colors: Vec<String>,
+    // Every bag considered, as counts in the same order as `colors`, with its
+    // likelihood and posterior probability
+    candidates: Vec<Candidate>,
+}
+
+struct Candidate {
+    counts: Vec<usize>,
+    log_likelihood: f64,
+    probability: f64,
+}
+
+// The estimates for one colour. The interval holds `mass` of the posterior,
+// with the same amount left over on either side.
+#[derive(PartialEq, Debug)]
+pub struct ColorEstimate {
+    pub color: String,
+    pub maximum_likelihood: usize,
+    pub lower: usize,
+    pub upper: usize,
+}
+
+impl BagPosterior {
+    // The number of bags considered grows as `max_count` to the power of the
+    // number of colours, so keep both small
+    pub fn new(draws: &[&CubeSet], max_count: usize) -> BagPosterior {
+        let seen = draws
+            .iter()
+            .fold(CubeSet::new(), |seen, draw| seen.union(draw));
+        let colors: Vec<String> = seen.colors().map(|(color, _)| color.to_string()).collect();
+        let ranges: Vec<(usize, usize)> = seen
+            .colors()
+            .map(|(_, count)| (count, max_count.max(count)))
+            .collect();
+
+        let ln_factorials = ln_factorials(ranges.iter().map(|(_, upper)| upper).sum());
+        let draw_counts: Vec<Vec<usize>> = draws
+            .iter()
+            .map(|draw| colors.iter().map(|color| draw.count(color)).collect())
+            .collect();
+
+        let mut candidates = vec![];
+        let mut counts: Vec<usize> = ranges.iter().map(|(lower, _)| *lower).collect();
+        loop {
+            let log_likelihood = draw_counts
+                .iter()
+                .map(|draw| draw_log_likelihood(&ln_factorials, &counts, draw))
+                .sum();
+            candidates.push(Candidate {
+                counts: counts.clone(),
+                log_likelihood,
+                probability: 0.0,
+            });
+
+            if !next_counts(&mut counts, &ranges) {
+                break;
+            }
+        }
+
+        // Normalised in log space, as likelihoods over many draws are tiny
+        let max_log_likelihood = candidates
+            .iter()
+            .map(|candidate| candidate.log_likelihood)
+            .fold(f64::NEG_INFINITY, f64::max);
+        let total: f64 = candidates
+            .iter()
+            .map(|candidate| (candidate.log_likelihood - max_log_likelihood).exp())
+            .sum();
+        for candidate in &mut candidates {
+            candidate.probability = (candidate.log_likelihood - max_log_likelihood).exp() / total;
+        }
+
+        BagPosterior { colors, candidates }
+    }
+
+    // The bag that makes the draws most likely. Ties go to the bag with the
+    // fewest cubes.
+    pub fn maximum_likelihood(&self) -> CubeSet {
+        let best = self
+            .candidates
+            .iter()
+            .reduce(|best, candidate| {
+                let better = candidate.log_likelihood > best.log_likelihood
+                    || (candidate.log_likelihood == best.log_likelihood
+                        && candidate.counts.iter().sum::<usize>()
+                            < best.counts.iter().sum::<usize>());
+                if better {
+                    candidate
+                } else {
+                    best
+                }
+            })
+            .expect("there is always at least one candidate bag");
+
+        self.bag(&best.counts)
+    }
+
+    // The probability of each number of cubes of `color`, fewest first
+    pub fn marginal(&self, color: &str) -> Vec<(usize, f64)> {
+        let Some(index) = self.colors.iter().position(|known| known == color) else {
+            return vec![(0, 1.0)];
+        };
+
+        let mut marginal: Vec<(usize, f64)> = vec![];
+        for candidate in &self.candidates {
+            let count = candidate.counts[index];
+            match marginal.iter_mut().find(|(known, _)| *known == count) {
+                Some((_, probability)) => *probability += candidate.probability,
+                None => marginal.push((count, candidate.probability)),
+            }
+        }
+        marginal.sort_by_key(|(count, _)| *count);
+        marginal
+    }
+
+    // The range of counts of `color` holding `mass` of the posterior, e.g.
+    // 0.9 for a 90% credible interval
+    pub fn credible_interval(&self, color: &str, mass: f64) -> (usize, usize) {
+        let marginal = self.marginal(color);
+        let tail = (1.0 - mass) / 2.0;
+
+        let mut cumulative = 0.0;
+        let mut lower = None;
+        let mut upper = marginal.last().expect("marginals are never empty").0;
+        for (count, probability) in marginal {
+            cumulative += probability;
+            if lower.is_none() && cumulative > tail {
+                lower = Some(count);
+            }
+            if cumulative >= 1.0 - tail {
+                upper = count;
+                break;
+            }
+        }
+
+        (lower.unwrap_or(upper), upper)
+    }
+
+    pub fn estimates(&self, mass: f64) -> Vec<ColorEstimate> {
+        let maximum_likelihood = self.maximum_likelihood();
+        self.colors
+            .iter()
+            .map(|color| {
+                let (lower, upper) = self.credible_interval(color, mass);
+                ColorEstimate {
+                    color: color.clone(),
+                    maximum_likelihood: maximum_likelihood.count(color),
+                    lower,
+                    upper,
+                }
+            })
+            .collect()
+    }
+
+    fn bag(&self, counts: &[usize]) -> CubeSet {
+        CubeSet::from_counts(
+            self.colors
+                .iter()
+                .zip(counts)
+                .map(|(color, count)| (color.as_str(), *count)),
+        )
+    }
+}
+
+// The log of the probability of taking exactly `draws` out of `bag` at once
+pub fn log_likelihood(draws: &[&CubeSet], bag: &CubeSet) -> f64 {
+    let seen = draws
+        .iter()
+        .fold(bag.clone(), |seen, draw| seen.union(draw));
+    let colors: Vec<&str> = seen.colors().map(|(color, _)| color).collect();
+    let counts: Vec<usize> = colors.iter().map(|color| bag.count(color)).collect();
+    let ln_factorials = ln_factorials(bag.total());
+
+    draws
+        .iter()
+        .map(|draw| {
+            let draw: Vec<usize> = colors.iter().map(|color| draw.count(color)).collect();
+            draw_log_likelihood(&ln_factorials, &counts, &draw)
+        })
+        .sum()
+}
+
+// Multivariate hypergeometric: the ways of choosing each colour's cubes over
+// the ways of choosing that many cubes from the whole bag
+fn draw_log_likelihood(ln_factorials: &[f64], bag: &[usize], draw: &[usize]) -> f64 {
+    if draw.iter().zip(bag).any(|(drawn, count)| drawn > count) {
+        return f64::NEG_INFINITY;
+    }
+
+    let ln_choose = |n: usize, k: usize| ln_factorials[n] - ln_factorials[k] - ln_factorials[n - k];
+    let ways: f64 = bag
+        .iter()
+        .zip(draw)
+        .map(|(count, drawn)| ln_choose(*count, *drawn))
+        .sum();
+
+    ways - ln_choose(bag.iter().sum(), draw.iter().sum())
+}
+
+// ln(n!) for every n up to `max`
+fn ln_factorials(max: usize) -> Vec<f64> {
+    let mut ln_factorials = vec![0.0];
+    for n in 1..=max {
+        ln_factorials.push(ln_factorials[n - 1] + (n as f64).ln());
+    }
+    ln_factorials
+}
+
+// Steps `counts` on to the next bag, like an odometer, returning false once
+// every bag has been seen
+fn next_counts(counts: &mut [usize], ranges: &[(usize, usize)]) -> bool {
+    for (count, (lower, upper)) in counts.iter_mut().zip(ranges) {
+        if *count < *upper {
+            *count += 1;
+            return true;
+        }
+        *count = *lower;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn draws(descriptions: &[&str]) -> Vec<CubeSet> {
+        descriptions
+            .iter()
+            .map(|description| CubeSet::from_str(description).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn likelihood() {
+        let draws = draws(&["3 red, 1 blue", "2 red, 2 blue", "4 red"]);
+        let draws: Vec<&CubeSet> = draws.iter().collect();
+        let bag = CubeSet::from_counts([("red", 4), ("blue", 2)]);
+
+        // 8/15 * 6/15 * 1/15
+        let expected = (48.0_f64 / 3375.0).ln();
+        assert!((expected - log_likelihood(&draws, &bag)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn impossible_bag() {
+        let draws = draws(&["3 red, 1 blue"]);
+        let draws: Vec<&CubeSet> = draws.iter().collect();
+
+        let too_few = CubeSet::from_counts([("red", 2), ("blue", 5)]);
+        assert_eq!(f64::NEG_INFINITY, log_likelihood(&draws, &too_few));
+        let missing_blue = CubeSet::from_counts([("red", 5)]);
+        assert_eq!(f64::NEG_INFINITY, log_likelihood(&draws, &missing_blue));
+    }
+
+    #[test]
+    fn single_draw_is_most_likely_the_whole_bag() {
+        let draws = draws(&["3 red, 1 blue"]);
+        let draws: Vec<&CubeSet> = draws.iter().collect();
+        let posterior = BagPosterior::new(&draws, 10);
+
+        assert_eq!(
+            CubeSet::from_counts([("red", 3), ("blue", 1)]),
+            posterior.maximum_likelihood()
+        );
+    }
+
+    #[test]
+    fn maximum_likelihood_matches_brute_force() {
+        let draws = draws(&["3 red, 1 blue", "2 red, 2 blue", "4 red"]);
+        let draws: Vec<&CubeSet> = draws.iter().collect();
+        let posterior = BagPosterior::new(&draws, 12);
+
+        let mut best = (f64::NEG_INFINITY, CubeSet::new());
+        for red in 4..=12 {
+            for blue in 2..=12 {
+                let bag = CubeSet::from_counts([("red", red), ("blue", blue)]);
+                let likelihood = log_likelihood(&draws, &bag);
+                if likelihood > best.0 {
+                    best = (likelihood, bag);
+                }
+            }
+        }
+
+        assert_eq!(best.1, posterior.maximum_likelihood());
+    }
+
+    #[test]
+    fn marginals_cover_every_count() {
+        let draws = draws(&["3 red, 1 blue", "2 red, 2 blue"]);
+        let draws: Vec<&CubeSet> = draws.iter().collect();
+        let posterior = BagPosterior::new(&draws, 8);
+
+        let marginal = posterior.marginal("red");
+        assert_eq!(
+            (3..=8).collect::<Vec<_>>(),
+            marginal.iter().map(|(count, _)| *count).collect::<Vec<_>>()
+        );
+        let total: f64 = marginal.iter().map(|(_, probability)| probability).sum();
+        assert!((1.0 - total).abs() < 1e-9);
+
+        assert_eq!(vec![(0, 1.0)], posterior.marginal("purple"));
+    }
+
+    #[test]
+    fn credible_interval() {
+        // Taking out every red cube is just as likely however many there are,
+        // so each count from 2 to 5 gets a quarter of the posterior
+        let draws = draws(&["2 red"]);
+        let draws: Vec<&CubeSet> = draws.iter().collect();
+        let posterior = BagPosterior::new(&draws, 5);
+
+        assert_eq!((2, 5), posterior.credible_interval("red", 1.0));
+        assert_eq!((3, 4), posterior.credible_interval("red", 0.4));
+    }
+
+    #[test]
+    fn estimates() {
+        let draws = draws(&["3 red, 1 blue", "2 red, 2 blue", "4 red"]);
+        let draws: Vec<&CubeSet> = draws.iter().collect();
+        let posterior = BagPosterior::new(&draws, 12);
+        let maximum_likelihood = posterior.maximum_likelihood();
+
+        for estimate in posterior.estimates(0.9) {
+            assert_eq!(
+                maximum_likelihood.count(&estimate.color),
+                estimate.maximum_likelihood
+            );
+            assert!(estimate.lower <= estimate.upper);
+            assert!(estimate.upper <= 12);
+        }
+    }
+}
@@ -1,3 +1,49 @@
 pub mod game;
 
 pub mod cube_set;
+
+pub mod game_log;
+
+pub mod generator;
+
+pub mod inference;
+
+use aoc_common::error::ParseError;
+use aoc_common::solution::Solution;
+use cube_set::CubeSet;
+use game_log::GameLog;
+
+// The colours the puzzle's cubes come in
+pub const PUZZLE_COLORS: [&str; 3] = ["red", "green", "blue"];
+
+// The bag part 1 asks about
+pub fn puzzle_bag() -> CubeSet {
+    CubeSet::from_counts([("red", 12), ("green", 13), ("blue", 14)])
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Puzzle = GameLog;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<GameLog, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(log: &GameLog) -> Result<usize, ParseError> {
+        Ok(log.possible_games(&puzzle_bag()).map(|game| game.id).sum())
+    }
+
+    fn part_2(log: &GameLog) -> Result<usize, ParseError> {
+        Ok(log
+            .games()
+            .iter()
+            .map(|game| game.minimum_starting_cube_set())
+            .map(|cube_set| cube_set.power(PUZZLE_COLORS))
+            .sum())
+    }
+}
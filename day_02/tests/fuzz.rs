@@ -0,0 +1,88 @@
+use aoc_common::diagnostic;
+use day_02::cube_set::CubeSet;
+use day_02::game::Game;
+use day_02::game_log::GameLog;
+use day_02::generator::LogGenerator;
+use proptest::prelude::*;
+
+// Parsing should only ever fail with an error, and that error should always
+// be renderable against the input it came from
+fn parse_without_panicking(log: &str) {
+    if let Err(err) = log.parse::<GameLog>() {
+        diagnostic::render(log, &err);
+    }
+    for line in log.lines() {
+        if let Err(err) = line.parse::<Game>() {
+            diagnostic::render(line, &err);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn arbitrary_text(log in any::<String>()) {
+        parse_without_panicking(&log);
+    }
+
+    // Close enough to a game log to get past the first few checks
+    #[test]
+    fn nearly_games(
+        log in "(Game [0-9]{0,3}: ?([0-9]{0,3} ?[a-zé]{0,6}[,;]? ?){0,6}\n?){0,5}",
+    ) {
+        parse_without_panicking(&log);
+    }
+
+    #[test]
+    fn nearly_cube_sets(description in "[ 0-9a-z,]{0,30}") {
+        if let Err(err) = description.parse::<CubeSet>() {
+            diagnostic::render(&description, &err);
+        }
+    }
+
+    #[test]
+    fn malformed_logs(seed in any::<u64>(), malformation_rate in 0.0..=1.0) {
+        let generator = LogGenerator {
+            games: 20,
+            malformation_rate,
+            ..LogGenerator::default()
+        };
+
+        for line in generator.lines(seed) {
+            parse_without_panicking(&line.text);
+            if line.malformation.is_none() {
+                prop_assert!(line.text.parse::<Game>().is_ok(), "{}", line.text);
+            }
+        }
+    }
+
+    // Mangling generated logs by deleting, duplicating or swapping bytes
+    #[test]
+    fn mangled_logs(
+        seed in any::<u64>(),
+        edits in prop::collection::vec((any::<usize>(), 0..3u8), 1..10),
+    ) {
+        let generator = LogGenerator {
+            games: 5,
+            ..LogGenerator::default()
+        };
+        let mut log = generator.log(seed).into_bytes();
+
+        for (position, edit) in edits {
+            if log.is_empty() {
+                break;
+            }
+            let position = position % log.len();
+            let next = (position + 1) % log.len();
+            match edit {
+                0 => {
+                    log.remove(position);
+                }
+                1 => log.insert(position, log[position]),
+                _ => log.swap(position, next),
+            }
+        }
+
+        // Only ASCII is generated, so the edits can't split a character
+        parse_without_panicking(&String::from_utf8(log).expect("still ASCII"));
+    }
+}
@@ -0,0 +1,13 @@
+use aoc_common::solution::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_03::schematic::Schematic;
+use day_03::Day03;
+
+fn schematic_from(c: &mut Criterion) {
+    c.bench_function("Schematic::from", |b| {
+        b.iter(|| Schematic::from(black_box(Day03::INPUT)))
+    });
+}
+
+criterion_group!(benches, schematic_from);
+criterion_main!(benches);
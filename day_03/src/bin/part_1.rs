@@ -1,25 +1,10 @@
-use day_03::number::Number;
-use day_03::schematic::Schematic;
+use aoc_common::input;
+use aoc_common::solution::{self, Solution};
+use day_03::Day03;
 
 fn main() {
-    let input = include_str!("../input.txt");
-    let schematic = Schematic::from(input);
-
-    let mut part_numbers: Vec<Number> = vec![];
-    for number in schematic.numbers {
-        if schematic
-            .symbols
-            .iter()
-            .any(|symbol| number.is_adjacent(&symbol))
-        {
-            part_numbers.push(number)
-        }
-    }
-
-    let sum: usize = part_numbers
-        .iter()
-        .map(|part_number| part_number.value)
-        .sum();
+    let input = input::load(Day03::INPUT);
+    let sum = solution::solve_or_exit::<Day03>(1, &input);
 
     println!("{sum}");
 }
@@ -1,28 +1,10 @@
-use day_03::number::Number;
-use day_03::schematic::Schematic;
+use aoc_common::input;
+use aoc_common::solution::{self, Solution};
+use day_03::Day03;
 
 fn main() {
-    let input = include_str!("../input.txt");
-    let schematic = Schematic::from(input);
+    let input = input::load(Day03::INPUT);
+    let sum = solution::solve_or_exit::<Day03>(2, &input);
 
-    let stars: Vec<_> = schematic
-        .symbols
-        .iter()
-        .filter(|symbol| symbol.value == '*')
-        .collect();
-
-    let mut gear_ratios = vec![];
-    for star in stars {
-        let adjacent_part_numbers: Vec<&Number> = schematic
-            .numbers
-            .iter()
-            .filter(|number| number.is_adjacent(star))
-            .collect();
-
-        if adjacent_part_numbers.len() == 2 {
-            gear_ratios.push(adjacent_part_numbers[0].value * adjacent_part_numbers[1].value)
-        }
-    }
-
-    println!("{:?}", gear_ratios.iter().sum::<usize>());
+    println!("{sum}");
 }
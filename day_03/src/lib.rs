@@ -1,56 +1,173 @@
-pub mod symbol;
 pub mod number;
+pub mod symbol;
+
+use aoc_common::error::ParseError;
+use aoc_common::solution::Solution;
+use schematic::Schematic;
+use symbol::Symbol;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Puzzle = Schematic;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Schematic, ParseError> {
+        Schematic::from(input)
+    }
+
+    fn part_1(schematic: &Schematic) -> Result<usize, ParseError> {
+        Ok(schematic
+            .numbers
+            .iter()
+            .filter(|number| {
+                number
+                    .points()
+                    .flat_map(|digit| schematic.grid.neighbors_8(digit))
+                    .any(|point| Symbol::is_symbol(schematic.grid[point]))
+            })
+            .map(|part_number| part_number.value)
+            .sum())
+    }
+
+    fn part_2(schematic: &Schematic) -> Result<usize, ParseError> {
+        Ok(schematic
+            .symbols
+            .iter()
+            .filter(|symbol| symbol.value == '*')
+            .map(|star| schematic.numbers_around(star.position))
+            .filter(|adjacent_part_numbers| adjacent_part_numbers.len() == 2)
+            .map(|gear| gear[0].value * gear[1].value)
+            .sum())
+    }
+}
 
 pub mod schematic {
-    use regex::Regex;
     use crate::number::Number;
     use crate::symbol::Symbol;
+    use aoc_common::error::{ParseError, ParseErrorKind};
+    use aoc_common::grid::{Grid, Point};
 
     #[derive(Debug)]
     pub struct Schematic {
+        pub grid: Grid<char>,
         pub numbers: Vec<Number>,
         pub symbols: Vec<Symbol>,
+        // Which of `numbers`, if any, covers each cell
+        number_at: Grid<Option<usize>>,
     }
 
     impl Schematic {
-        pub fn from(input: &str) -> Schematic {
-            let r = Regex::new(Symbol::SYMBOL_REGEX).unwrap();
-            let mut schematic = Schematic {
-                numbers: vec![],
-                symbols: vec![],
-            };
-
-            for (y, line) in input.lines().enumerate() {
-                let characters: Vec<char> = line.chars().collect();
+        pub fn from(input: &str) -> Result<Schematic, ParseError> {
+            let grid = Grid::parse(input, |c| c)?;
+            let mut number_at = grid.map(|_| None);
+            let mut numbers = vec![];
+
+            for (y, row) in grid.rows().enumerate() {
                 let mut x = 0;
 
-                while x < characters.len() {
-                    if r.is_match(&characters[x].to_string()) {
-                        schematic.symbols.push(Symbol::new(x, y, characters[x]))
-                    } else if characters[x].is_numeric() {
-                        let mut number = Number {
-                            x_start: x,
-                            x_end: 0,
-                            y,
-                            value: 0,
-                        };
-
-                        let mut number_string = characters[x].to_string();
-                        while x + 1 < characters.len() && characters[x + 1].is_numeric() {
-                            x += 1;
-                            number_string.push_str(&characters[x].to_string())
-                        }
-
-                        number.x_end = x;
-                        number.value = number_string.parse().unwrap();
-                        schematic.numbers.push(number);
+                while x < row.len() {
+                    let len = row[x..].iter().take_while(|c| c.is_numeric()).count();
+                    if len == 0 {
+                        x += 1;
+                        continue;
                     }
 
-                    x += 1;
+                    let digits: String = row[x..x + len].iter().collect();
+                    let number = Number {
+                        start: Point::new(x, y),
+                        len,
+                        value: digits.parse().map_err(|_| ParseError {
+                            kind: ParseErrorKind::InvalidNumber,
+                            line: y + 1,
+                            column: x + 1,
+                            text: digits.clone(),
+                        })?,
+                    };
+
+                    for point in number.points() {
+                        number_at[point] = Some(numbers.len());
+                    }
+                    numbers.push(number);
+                    x += len;
                 }
             }
 
-            schematic
+            let symbols = grid
+                .iter()
+                .filter(|(_, c)| Symbol::is_symbol(**c))
+                .map(|(point, c)| Symbol::new(point, *c))
+                .collect();
+
+            Ok(Schematic {
+                grid,
+                numbers,
+                symbols,
+                number_at,
+            })
+        }
+
+        // Every number touching `point`, diagonals included, each only once
+        pub fn numbers_around(&self, point: Point) -> Vec<&Number> {
+            let mut indices: Vec<usize> = self
+                .grid
+                .neighbors_8(point)
+                .filter_map(|neighbor| self.number_at[neighbor])
+                .collect();
+            indices.sort();
+            indices.dedup();
+
+            indices.into_iter().map(|i| &self.numbers[i]).collect()
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::schematic::Schematic;
+    use aoc_common::error::ParseErrorKind;
+    use aoc_common::grid::Point;
+
+    #[test]
+    fn number_too_large() {
+        let error =
+            Schematic::from("...*............................\n.123456789012345678901234567890.")
+                .unwrap_err();
+        assert_eq!(ParseErrorKind::InvalidNumber, error.kind);
+        assert_eq!(2, error.line);
+        assert_eq!(2, error.column);
+    }
+
+    #[test]
+    fn ragged_lines() {
+        let error = Schematic::from("467..\n...*\n..35.").unwrap_err();
+        assert_eq!(2, error.line);
+    }
+
+    #[test]
+    fn numbers_and_symbols() {
+        let schematic = Schematic::from("467..\n...*.\n..35.").unwrap();
+        let values: Vec<usize> = schematic.numbers.iter().map(|n| n.value).collect();
+
+        assert_eq!(vec![467, 35], values);
+        assert_eq!(Point::new(2, 2), schematic.numbers[1].start);
+        assert_eq!(1, schematic.symbols.len());
+        assert_eq!(Point::new(3, 1), schematic.symbols[0].position);
+    }
+
+    #[test]
+    fn numbers_around() {
+        let schematic = Schematic::from("467..\n...*.\n..35.").unwrap();
+        let values: Vec<usize> = schematic
+            .numbers_around(Point::new(3, 1))
+            .iter()
+            .map(|n| n.value)
+            .collect();
+
+        assert_eq!(vec![467, 35], values);
+        assert!(schematic.numbers_around(Point::new(0, 2)).is_empty());
+    }
+}
@@ -1,73 +1,80 @@
-use crate::symbol::Symbol;
+use aoc_common::grid::{Adjacency, Point};
 
+// A run of digits along one row
 #[derive(Debug)]
 pub struct Number {
-    pub x_start: usize,
-    pub x_end: usize,
-    pub y: usize,
+    pub start: Point,
+    pub len: usize,
     pub value: usize,
 }
 
 impl Number {
-    // TODO: How do I make this more generic to not demand a symbol, but instead
-    //       just want something that has an x and a y?
-    pub fn is_adjacent(&self, symbol: &Symbol) -> bool {
-        self.y.checked_sub(1).unwrap_or(0) <= symbol.y
-            && symbol.y <= self.y + 1
-            && self.x_start.checked_sub(1).unwrap_or(0) <= symbol.x
-            && symbol.x <= self.x_end + 1
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (self.start.x..self.start.x + self.len).map(|x| Point::new(x, self.start.y))
+    }
+
+    // Whether `point` touches any digit, diagonals included
+    pub fn is_adjacent(&self, point: Point) -> bool {
+        self.points()
+            .any(|digit| digit.is_neighbor(point, Adjacency::Eight))
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{Number, Symbol};
+    use super::{Number, Point};
 
     #[test]
     fn adjacent() {
         let number = Number {
-            x_start: 2,
-            x_end: 4,
-            y: 2,
+            start: Point::new(2, 2),
+            len: 3,
             value: 456,
         };
-        let mut symbols: Vec<Symbol> = Vec::new();
 
         for x in 1..=5 {
             for y in 1..=3 {
-                symbols.push(Symbol::new(x, y, '*'))
+                assert!(number.is_adjacent(Point::new(x, y)))
             }
         }
-
-        for symbol in symbols {
-            assert_eq!(true, number.is_adjacent(&symbol))
-        }
     }
 
     #[test]
     fn is_not_adjacent() {
         let number = Number {
-            x_start: 2,
-            x_end: 4,
-            y: 2,
+            start: Point::new(2, 2),
+            len: 3,
             value: 456,
         };
-        let mut symbols: Vec<Symbol> = Vec::new();
+        let mut points: Vec<Point> = Vec::new();
 
         for x in 0..=6 {
             for y in 0..=4 {
                 if y == 1 || y == 2 || y == 3 {
                     if x == 0 || x == 6 {
-                        symbols.push(Symbol::new(x, y, '*'))
+                        points.push(Point::new(x, y))
                     }
                 } else {
-                    symbols.push(Symbol::new(x, y, '*'))
+                    points.push(Point::new(x, y))
                 }
             }
         }
 
-        for symbol in symbols {
-            assert_eq!(false, number.is_adjacent(&symbol))
+        for point in points {
+            assert!(!number.is_adjacent(point))
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn points() {
+        let number = Number {
+            start: Point::new(2, 2),
+            len: 3,
+            value: 456,
+        };
+        assert_eq!(
+            vec![Point::new(2, 2), Point::new(3, 2), Point::new(4, 2)],
+            number.points().collect::<Vec<_>>()
+        );
+    }
+}
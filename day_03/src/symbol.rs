@@ -1,16 +1,18 @@
-use crate::number::Number;
+use aoc_common::grid::Point;
 
 #[derive(Debug)]
 pub struct Symbol {
-    pub x: usize,
-    pub y: usize,
+    pub position: Point,
     pub value: char,
 }
 
 impl Symbol {
-    pub const SYMBOL_REGEX: &str = r"[^a-zA-Z0-9. ]";
+    pub fn new(position: Point, value: char) -> Symbol {
+        Symbol { position, value }
+    }
 
-    pub fn new(x: usize, y: usize, value: char) -> Symbol {
-        Symbol { x, y, value }
+    // Anything other than a digit, a letter or a blank '.'
+    pub fn is_symbol(c: char) -> bool {
+        !(c.is_ascii_alphanumeric() || c == '.' || c == ' ')
     }
-}
\ No newline at end of file
+}
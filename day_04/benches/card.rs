@@ -0,0 +1,19 @@
+use aoc_common::solution::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_04::Day04;
+
+fn how_many_wins(c: &mut Criterion) {
+    let cards = Day04::parse(Day04::INPUT).expect("bundled input parses");
+
+    c.bench_function("Card::how_many_wins", |b| {
+        b.iter(|| {
+            black_box(&cards)
+                .iter()
+                .map(|card| card.how_many_wins())
+                .sum::<u32>()
+        })
+    });
+}
+
+criterion_group!(benches, how_many_wins);
+criterion_main!(benches);
@@ -1,12 +1,10 @@
-use day_04::Card;
+use aoc_common::input;
+use aoc_common::solution::{self, Solution};
+use day_04::Day04;
 
 fn main() {
-    let input = include_str!("../input.txt");
-    let card_pile_worth: u32 = input
-        .lines()
-        .map(|line| Card::from(line))
-        .map(|card| card.worth())
-        .sum();
+    let input = input::load(Day04::INPUT);
+    let card_pile_worth = solution::solve_or_exit::<Day04>(1, &input);
 
     println!("{card_pile_worth}");
 }
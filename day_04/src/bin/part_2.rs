@@ -1,26 +1,10 @@
-use day_04::{Card, CardPile};
+use aoc_common::input;
+use aoc_common::solution::{self, Solution};
+use day_04::Day04;
 
 fn main() {
-    let input = include_str!("../input.txt");
-    let cards: Vec<Card> = input
-        .lines()
-        .map(|line| Card::from(line))
-        .collect();
+    let input = input::load(Day04::INPUT);
+    let total_cards = solution::solve_or_exit::<Day04>(2, &input);
 
-    let mut card_pile = CardPile::new(cards);
-    let greatest_card_id_in_pile = card_pile.cards.last().unwrap().id;
-
-    for i in 1..=greatest_card_id_in_pile {
-        let card = card_pile.get_original_card_by_id(i);
-        let wins = card.how_many_wins();
-
-        // Do this part once for every copy of the current card
-        for _ in 0..card_pile.card_counts[&card.id] {
-            for j in i+1..=i + wins {
-                card_pile.add_copy_of_card_with_id(j);
-            }
-        }
-    }
-
-    println!("{}", card_pile.total_cards());
+    println!("{total_cards}");
 }
@@ -1,11 +1,81 @@
-use std::collections::HashMap;
+use aoc_common::error::ParseError;
+use aoc_common::parse;
+use aoc_common::solution::Solution;
 use regex::Regex;
+use std::collections::HashMap;
 
 const CARD_DESCRIPTION_PATTERN: &str = r"Card +([0-9]+): (.+) \| (.+)";
 
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Puzzle = Vec<Card>;
+    type Answer = u32;
+
+    // Part 2 looks cards up by id, so they have to be numbered 1, 2, 3, ... in
+    // order
+    fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+        let mut cards = vec![];
+
+        for (i, line) in input.lines().enumerate() {
+            let card = Card::from(line).map_err(|err| err.on_line(i + 1))?;
+
+            let expected_id = cards.len() as u32 + 1;
+            if card.id != expected_id {
+                let id = line
+                    .split(':')
+                    .next()
+                    .and_then(|card| card.split_whitespace().nth(1))
+                    .unwrap_or(line);
+                let expected = format!("card number {expected_id}");
+                return Err(ParseError::expected(&expected, line, id).on_line(i + 1));
+            }
+
+            cards.push(card);
+        }
+
+        if cards.is_empty() {
+            let past_end = input.lines().count() + 1;
+            return Err(ParseError::expected("at least one card", "", "").on_line(past_end));
+        }
+
+        Ok(cards)
+    }
+
+    fn part_1(cards: &Vec<Card>) -> Result<u32, ParseError> {
+        Ok(cards.iter().map(|card| card.worth()).sum())
+    }
+
+    fn part_2(cards: &Vec<Card>) -> Result<u32, ParseError> {
+        let mut card_pile = CardPile::new(cards.clone());
+        let greatest_card_id_in_pile = card_pile
+            .cards
+            .last()
+            .expect("parse checks there's at least one card")
+            .id;
+
+        for i in 1..=greatest_card_id_in_pile {
+            let card = card_pile.get_original_card_by_id(i);
+            let wins = card.how_many_wins();
+
+            // Do this part once for every copy of the current card
+            for _ in 0..card_pile.card_counts[&card.id] {
+                for j in i + 1..=i + wins {
+                    card_pile.add_copy_of_card_with_id(j);
+                }
+            }
+        }
+
+        Ok(card_pile.total_cards())
+    }
+}
+
 pub struct CardPile {
     pub cards: Vec<Card>,
-    pub card_counts: HashMap<u32, u32>
+    pub card_counts: HashMap<u32, u32>,
 }
 
 impl CardPile {
@@ -16,10 +86,7 @@ impl CardPile {
             *count += 1;
         }
 
-        CardPile {
-            cards,
-            card_counts,
-        }
+        CardPile { cards, card_counts }
     }
 
     pub fn get_original_card_by_id(&self, id: u32) -> &Card {
@@ -32,11 +99,14 @@ impl CardPile {
     }
 
     pub fn total_cards(&self) -> u32 {
-        self.card_counts.keys().map(|key| self.card_counts[key] ).sum()
+        self.card_counts
+            .keys()
+            .map(|key| self.card_counts[key])
+            .sum()
     }
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct Card {
     pub id: u32,
     owned_numbers: Vec<u32>,
@@ -44,28 +114,18 @@ pub struct Card {
 }
 
 impl Card {
-    pub fn from(card_description: &str) -> Card {
+    pub fn from(card_description: &str) -> Result<Card, ParseError> {
         let card_description_regex = Regex::new(CARD_DESCRIPTION_PATTERN).expect("valid regex");
-        let (_, [card_id, winning_numbers, owned_numbers]) = card_description_regex
-            .captures(card_description)
-            .unwrap()
-            .extract::<3>();
-
-        Card {
-            id: card_id
-                .parse()
-                .expect("card id in description should be number"),
-            winning_numbers: winning_numbers
-                .trim()
-                .split_whitespace()
-                .map(|n| n.parse::<u32>().unwrap())
-                .collect(),
-            owned_numbers: owned_numbers
-                .trim()
-                .split_whitespace()
-                .map(|n| n.parse::<u32>().unwrap())
-                .collect(),
-        }
+        let [card_id, winning_numbers, owned_numbers] =
+            parse::captures(&card_description_regex, card_description)?;
+
+        Ok(Card {
+            id: parse::number(card_id).map_err(|err| err.within(card_description, card_id))?,
+            winning_numbers: parse::numbers(winning_numbers)
+                .map_err(|err| err.within(card_description, winning_numbers))?,
+            owned_numbers: parse::numbers(owned_numbers)
+                .map_err(|err| err.within(card_description, owned_numbers))?,
+        })
     }
 
     pub fn worth(&self) -> u32 {
@@ -75,7 +135,7 @@ impl Card {
             .filter(|owned_number| self.winning_numbers.contains(owned_number))
             .collect();
 
-        if matches.len() == 0 {
+        if matches.is_empty() {
             return 0;
         }
 
@@ -94,6 +154,7 @@ impl Card {
 #[cfg(test)]
 mod card_tests {
     use super::*;
+    use aoc_common::error::ParseErrorKind;
 
     #[test]
     fn card_worth_1() {
@@ -125,10 +186,24 @@ mod card_tests {
 
         assert_eq!(
             card,
-            Card::from("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53")
+            Card::from("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap()
         );
     }
 
+    #[test]
+    fn card_description_with_invalid_number() {
+        let error = Card::from("Card 1: 41 48 83 86 17 | 83 86  6 3l 17  9 48 53").unwrap_err();
+        assert_eq!(ParseErrorKind::InvalidNumber, error.kind);
+        assert_eq!(35, error.column);
+        assert_eq!("3l", error.text);
+    }
+
+    #[test]
+    fn card_description_without_separator() {
+        let error = Card::from("Card 1: 41 48 83 86 17 83 86  6 31 17  9 48 53").unwrap_err();
+        assert!(matches!(error.kind, ParseErrorKind::NoMatch { .. }));
+    }
+
     #[test]
     fn card_worth_2() {
         let card = Card {
@@ -148,7 +223,7 @@ mod card_tests {
         };
         assert_eq!(
             card,
-            Card::from("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19")
+            Card::from("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19").unwrap()
         )
     }
 
@@ -171,7 +246,7 @@ mod card_tests {
         };
         assert_eq!(
             card,
-            Card::from("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1")
+            Card::from("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1").unwrap()
         )
     }
 
@@ -194,7 +269,7 @@ mod card_tests {
         };
         assert_eq!(
             card,
-            Card::from("Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83")
+            Card::from("Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83").unwrap()
         )
     }
 
@@ -227,7 +302,7 @@ mod card_tests {
         };
         assert_eq!(
             card,
-            Card::from("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36")
+            Card::from("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36").unwrap()
         )
     }
 }
@@ -238,8 +313,8 @@ mod card_pile_tests {
 
     #[test]
     fn test_pile_constructor() {
-        let card_2 = Card::from("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19");
-        let card_5 = Card::from("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36");
+        let card_2 = Card::from("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19").unwrap();
+        let card_5 = Card::from("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36").unwrap();
 
         let pile = CardPile::new(vec![card_2, card_5]);
 
@@ -250,8 +325,8 @@ mod card_pile_tests {
 
     #[test]
     fn test_pile_add_copy() {
-        let card_2 = Card::from("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19");
-        let card_5 = Card::from("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36");
+        let card_2 = Card::from("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19").unwrap();
+        let card_5 = Card::from("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36").unwrap();
 
         let mut pile = CardPile::new(vec![card_2, card_5]);
         pile.add_copy_of_card_with_id(2);
@@ -260,10 +335,41 @@ mod card_pile_tests {
 
     #[test]
     fn test_pile_total_cards() {
-        let card_2 = Card::from("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19");
-        let card_5 = Card::from("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36");
+        let card_2 = Card::from("Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19").unwrap();
+        let card_5 = Card::from("Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36").unwrap();
 
         let pile = CardPile::new(vec![card_2, card_5]);
         assert_eq!(2, pile.total_cards());
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::*;
+
+    #[test]
+    fn no_cards() {
+        let error = Day04::parse("").unwrap_err();
+        assert_eq!(
+            "line 1, column 1: expected at least one card, found nothing",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn not_starting_at_1() {
+        let error = Day04::parse("Card 3: 41 48 | 83 86\n").unwrap_err();
+        assert_eq!(
+            "line 1, column 6: expected card number 1, found \"3\"",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn out_of_order() {
+        let error = Day04::parse("Card 1: 41 48 | 83 86\nCard  3: 41 48 | 83 86\n").unwrap_err();
+        assert_eq!(2, error.line);
+        assert_eq!(7, error.column);
+        assert_eq!("3", error.text);
+    }
+}
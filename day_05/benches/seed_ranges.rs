@@ -0,0 +1,27 @@
+use aoc_common::solution::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_05::Day05;
+
+// The full part 2 scan takes minutes, so this only scans the start of each
+// seed range. That's plenty to see a change in how fast locations are found.
+const SEEDS_PER_RANGE: u64 = 10_000;
+
+fn seed_range_scan(c: &mut Criterion) {
+    let almanac = Day05::parse(Day05::INPUT).expect("bundled input parses");
+    let seed_ranges = almanac
+        .seed_ranges()
+        .expect("bundled input has seed ranges");
+
+    c.bench_function("seed range scan", |b| {
+        b.iter(|| {
+            seed_ranges
+                .iter()
+                .flat_map(|range| range.start..(range.start + SEEDS_PER_RANGE).min(range.end))
+                .map(|seed| almanac.location(black_box(seed)))
+                .min()
+        })
+    });
+}
+
+criterion_group!(benches, seed_range_scan);
+criterion_main!(benches);
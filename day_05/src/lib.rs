@@ -1,14 +1,426 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
+use aoc_common::error::ParseError;
+use aoc_common::parse;
+use aoc_common::solution::Solution;
+use rangemap::RangeMap;
+use regex::Regex;
+use std::ops::Range;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Puzzle = Almanac;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        Almanac::from(input)
+    }
+
+    fn part_1(almanac: &Almanac) -> Result<u64, ParseError> {
+        Ok(almanac
+            .seeds
+            .iter()
+            .map(|seed| almanac.location(*seed))
+            .min()
+            .expect("parse checks there's at least one seed"))
+    }
+
+    fn part_2(almanac: &Almanac) -> Result<u64, ParseError> {
+        Ok(almanac
+            .seed_ranges()?
+            .into_iter()
+            .flatten()
+            .map(|seed| almanac.location(seed))
+            .min()
+            .expect("seed_ranges checks there's at least one seed in a range"))
+    }
+}
+
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    // The line the seeds came from and its number, for errors when reading
+    // them as ranges
+    seeds_line: (usize, String),
+    // In the order they appear in the input: seed-to-soil, soil-to-fertilizer, ...
+    pub maps: Vec<AlmanacMap>,
+}
+
+impl Almanac {
+    pub fn from(almanac_description: &str) -> Result<Almanac, ParseError> {
+        let mut input = almanac_description.lines().enumerate().peekable();
+
+        let inital_seed_regex = Regex::new(r"^seeds: (.+)$").expect("valid regex");
+        let map_header_regex = Regex::new(r"^([a-z]+)-to-([a-z]+) map:$").expect("valid regex");
+        let map_data_regex = Regex::new(r"^([0-9]+) +([0-9]+) +([0-9]+)$").expect("valid regex");
+
+        let mut almanac = Almanac {
+            seeds: vec![],
+            seeds_line: (0, String::new()),
+            maps: vec![],
+        };
+
+        while let Some((i, line)) = input.next() {
+            if line.is_empty() {
+                continue;
+            }
+
+            if inital_seed_regex.is_match(line) {
+                let [seed_numbers] = parse::captures(&inital_seed_regex, line)?;
+                almanac.seeds = parse::numbers(seed_numbers)
+                    .map_err(|err| err.within(line, seed_numbers).on_line(i + 1))?;
+                almanac.seeds_line = (i + 1, line.to_string());
+            } else if map_header_regex.is_match(line) {
+                let mut map = AlmanacMap::new(vec![]);
+
+                while let Some((i, data)) = input.next_if(|(_, line)| !line.is_empty()) {
+                    let map_data: [&str; 3] =
+                        parse::captures(&map_data_regex, data).map_err(|err| err.on_line(i + 1))?;
+
+                    let mut numbers = [0; 3];
+                    for (number, text) in numbers.iter_mut().zip(map_data) {
+                        *number = parse::number::<u64>(text)
+                            .map_err(|err| err.within(data, text).on_line(i + 1))?;
+                    }
+                    let [dest_range_start, src_range_start, range_length] = numbers;
+
+                    let length_text = map_data[2];
+                    if range_length == 0 {
+                        return Err(ParseError::expected(
+                            "a range length above 0",
+                            data,
+                            length_text,
+                        )
+                        .on_line(i + 1));
+                    }
+                    if src_range_start.checked_add(range_length).is_none()
+                        || dest_range_start.checked_add(range_length).is_none()
+                    {
+                        return Err(ParseError::expected(
+                            "a range that ends within 64 bits",
+                            data,
+                            length_text,
+                        )
+                        .on_line(i + 1));
+                    }
+
+                    map.range_map.insert(
+                        src_range_start..src_range_start + range_length,
+                        RangeSpecifiedHashMap::new(src_range_start, dest_range_start, range_length),
+                    );
+                }
+
+                almanac.maps.push(map);
+            } else {
+                return Err(
+                    ParseError::expected("a seeds line or a map header", line, line).on_line(i + 1),
+                );
+            }
+        }
+
+        if almanac.seeds.is_empty() {
+            let past_end = almanac_description.lines().count() + 1;
+            return Err(ParseError::expected("a seeds line", "", "").on_line(past_end));
+        }
+
+        Ok(almanac)
+    }
+
+    // For part 2 the seeds line is read as pairs of (range start, range length)
+    pub fn seed_ranges(&self) -> Result<Vec<Range<u64>>, ParseError> {
+        let (line_number, line) = &self.seeds_line;
+        let seed_texts: Vec<&str> = line.split_whitespace().skip(1).collect();
+        let error = |expected: &str, offending: &str| {
+            ParseError::expected(expected, line, offending).on_line(*line_number)
+        };
+
+        if self.seeds.len() % 2 == 1 {
+            let last_seed = seed_texts.last().expect("there's at least one seed");
+            return Err(error("a length after each seed range start", last_seed));
+        }
+
+        let mut seed_ranges = vec![];
+        for (pair, texts) in self.seeds.chunks_exact(2).zip(seed_texts.chunks_exact(2)) {
+            let end = pair[0]
+                .checked_add(pair[1])
+                .ok_or_else(|| error("a seed range that ends within 64 bits", texts[1]))?;
+            seed_ranges.push(pair[0]..end);
+        }
+
+        if seed_ranges.iter().all(|range| range.is_empty()) {
+            return Err(error("a seed range with a length above 0", line));
+        }
+
+        Ok(seed_ranges)
+    }
+
+    pub fn location(&self, seed: u64) -> u64 {
+        self.maps.iter().fold(seed, |k, map| map.get(k))
+    }
+}
+
+pub struct AlmanacMap {
+    range_map: RangeMap<u64, RangeSpecifiedHashMap>,
+}
+
+impl AlmanacMap {
+    pub fn new(range_maps: Vec<RangeSpecifiedHashMap>) -> AlmanacMap {
+        let mut almanac = AlmanacMap {
+            range_map: RangeMap::new(),
+        };
+        for map in range_maps {
+            almanac
+                .range_map
+                .insert(map.source_start..map.source_start + map.range, map);
+        }
+
+        almanac
+    }
+
+    pub fn get(&self, k: u64) -> u64 {
+        if let Some(map) = self.range_map.get(&k) {
+            map.get(k).unwrap()
+        } else {
+            k
+        }
+    }
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
+mod almanac_tests {
+    use crate::{Almanac, Day05};
+    use aoc_common::error::ParseErrorKind;
+    use aoc_common::solution::Solution;
+
+    #[test]
+    fn malformed_map_row() {
+        let error = Almanac::from("seeds: 79 14\n\nseed-to-soil map:\n50 98\n")
+            .err()
+            .unwrap();
+        assert!(matches!(error.kind, ParseErrorKind::NoMatch { .. }));
+        assert_eq!(4, error.line);
+        assert_eq!("50 98", error.text);
+    }
+
+    #[test]
+    fn invalid_seed() {
+        let error = Almanac::from("seeds: 79 1x4\n").err().unwrap();
+        assert_eq!(ParseErrorKind::InvalidNumber, error.kind);
+        assert_eq!(1, error.line);
+        assert_eq!(11, error.column);
+    }
 
     #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
+    fn unrecognised_line() {
+        let error = Almanac::from("seeds: 79 14\n\nseed to soil\n")
+            .err()
+            .unwrap();
+        assert_eq!(3, error.line);
+        assert_eq!("seed to soil", error.text);
+    }
+
+    #[test]
+    fn empty_map_range() {
+        let error = Almanac::from("seeds: 79 14\n\nseed-to-soil map:\n1 2 0\n")
+            .err()
+            .unwrap();
+        assert_eq!(
+            ParseErrorKind::Expected("a range length above 0".to_string()),
+            error.kind
+        );
+        assert_eq!(4, error.line);
+        assert_eq!(5, error.column);
+    }
+
+    #[test]
+    fn map_range_past_u64() {
+        let error = Almanac::from("seeds: 79 14\n\nseed-to-soil map:\n1 18446744073709551615 5\n")
+            .err()
+            .unwrap();
+        assert_eq!(4, error.line);
+        assert_eq!(24, error.column);
+        assert_eq!("5", error.text);
+
+        let error = Almanac::from("seeds: 79 14\n\nseed-to-soil map:\n18446744073709551615 1 5\n")
+            .err()
+            .unwrap();
+        assert_eq!(4, error.line);
+    }
+
+    #[test]
+    fn seed_range_past_u64() {
+        let almanac = Almanac::from("seeds: 79 14 18446744073709551615 2\n").unwrap();
+        let error = Day05::part_2(&almanac).unwrap_err();
+        assert_eq!(
+            ParseErrorKind::Expected("a seed range that ends within 64 bits".to_string()),
+            error.kind
+        );
+        assert_eq!(1, error.line);
+        assert_eq!(35, error.column);
+    }
+
+    // Part 1 doesn't pair the seeds up, so only part 2 minds an odd one out
+    #[test]
+    fn unpaired_seed() {
+        for (seeds, lowest, column) in [("seeds: 79", 79, 8), ("seeds: 79 14 55", 14, 14)] {
+            let almanac = Almanac::from(&format!("{seeds}\n")).unwrap();
+            assert_eq!(Ok(lowest), Day05::part_1(&almanac));
+
+            let error = Day05::part_2(&almanac).unwrap_err();
+            assert_eq!(
+                ParseErrorKind::Expected("a length after each seed range start".to_string()),
+                error.kind
+            );
+            assert_eq!(1, error.line);
+            assert_eq!(column, error.column);
+        }
+    }
+
+    #[test]
+    fn no_seeds_in_ranges() {
+        let almanac = Almanac::from("seeds: 1 2\nseeds: 79 0 55 0\n").unwrap();
+        assert_eq!(Ok(0), Day05::part_1(&almanac));
+
+        let error = Day05::part_2(&almanac).unwrap_err();
+        assert_eq!(2, error.line);
+        assert_eq!("seeds: 79 0 55 0", error.text);
+    }
+
+    // The seeds line used to be read a pair short for part 2
+    #[test]
+    fn last_seed_range() {
+        let almanac = Almanac::from("seeds: 90 5 10 3\n").unwrap();
+        assert_eq!(Ok(vec![90..95, 10..13]), almanac.seed_ranges());
+        assert_eq!(10, Day05::part_2(&almanac).unwrap());
+    }
+
+    #[test]
+    fn missing_seeds() {
+        let error = Almanac::from("seed-to-soil map:\n50 98 2\n").err().unwrap();
+        assert_eq!(
+            ParseErrorKind::Expected("a seeds line".to_string()),
+            error.kind
+        );
+        assert_eq!(3, error.line);
+    }
+}
+
+#[cfg(test)]
+mod almanac_map_tests {
+    use crate::{AlmanacMap, RangeSpecifiedHashMap};
+
+    #[test]
+    fn test_source_start() {
+        let map = AlmanacMap::new(vec![
+            RangeSpecifiedHashMap::new(98, 50, 2),
+            RangeSpecifiedHashMap::new(50, 52, 48),
+        ]);
+
+        assert_eq!(50, map.get(98))
+    }
+
+    #[test]
+    fn test_middle() {
+        let map = AlmanacMap::new(vec![
+            RangeSpecifiedHashMap::new(98, 50, 2),
+            RangeSpecifiedHashMap::new(50, 52, 48),
+        ]);
+
+        assert_eq!(53, map.get(51))
+    }
+
+    #[test]
+    fn test_source_end() {
+        let map = AlmanacMap::new(vec![
+            RangeSpecifiedHashMap::new(98, 50, 2),
+            RangeSpecifiedHashMap::new(50, 52, 48),
+        ]);
+
+        assert_eq!(51, map.get(99))
+    }
+
+    #[test]
+    fn test_past_end() {
+        let map = AlmanacMap::new(vec![
+            RangeSpecifiedHashMap::new(98, 50, 2),
+            RangeSpecifiedHashMap::new(50, 52, 48),
+        ]);
+
+        assert_eq!(100, map.get(100))
+    }
+
+    #[test]
+    fn test_before_source_start() {
+        let map = AlmanacMap::new(vec![
+            RangeSpecifiedHashMap::new(98, 50, 2),
+            RangeSpecifiedHashMap::new(50, 52, 48),
+        ]);
+
+        assert_eq!(49, map.get(49))
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct RangeSpecifiedHashMap {
+    source_start: u64,
+    destination_start: u64,
+    range: u64,
+}
+
+impl RangeSpecifiedHashMap {
+    pub fn new(source_start: u64, destination_start: u64, range: u64) -> RangeSpecifiedHashMap {
+        RangeSpecifiedHashMap {
+            source_start,
+            destination_start,
+            range,
+        }
+    }
+
+    pub fn get(&self, k: u64) -> Option<u64> {
+        if k < self.source_start {
+            return None;
+        }
+
+        let difference = k - self.source_start;
+
+        if difference >= self.range {
+            return None;
+        }
+
+        Some(self.destination_start + difference)
+    }
+}
+
+#[cfg(test)]
+mod range_map_tests {
+    use crate::RangeSpecifiedHashMap;
+
+    #[test]
+    fn test_source_start() {
+        let map = RangeSpecifiedHashMap::new(98, 50, 2);
+
+        assert_eq!(Some(50), map.get(98))
+    }
+
+    #[test]
+    fn test_source_end() {
+        let map = RangeSpecifiedHashMap::new(98, 50, 2);
+
+        assert_eq!(Some(51), map.get(99))
+    }
+
+    #[test]
+    fn test_past_end() {
+        let map = RangeSpecifiedHashMap::new(98, 50, 2);
+
+        assert_eq!(None, map.get(100))
+    }
+
+    #[test]
+    fn test_before_source_start() {
+        let map = RangeSpecifiedHashMap::new(98, 50, 2);
+
+        assert_eq!(None, map.get(97))
     }
 }
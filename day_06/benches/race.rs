@@ -0,0 +1,16 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_06::Race;
+
+fn winning_button_hold_times(c: &mut Criterion) {
+    // The part 1 example races
+    c.bench_function("Race::winning_button_hold_times", |b| {
+        b.iter(|| {
+            [(7, 9), (15, 40), (30, 200)].map(|(duration, record)| {
+                Race::new(black_box(duration), black_box(record)).winning_button_hold_times()
+            })
+        })
+    });
+}
+
+criterion_group!(benches, winning_button_hold_times);
+criterion_main!(benches);
@@ -1,13 +1,10 @@
-use day_06::parse_races;
+use aoc_common::input;
+use aoc_common::solution::{self, Solution};
+use day_06::Day06;
 
 fn main() {
-    let input = include_str!("../input.txt");
-
-    let races = parse_races(input);
-    let margin_of_error = races
-        .iter()
-        .map(|race| race.winning_button_hold_times.len() as u32)
-        .product::<u32>();
+    let input = input::load(Day06::INPUT);
+    let margin_of_error = solution::solve_or_exit::<Day06>(1, &input);
 
     println!("{margin_of_error}");
 }
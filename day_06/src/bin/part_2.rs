@@ -1,8 +1,9 @@
-use day_06::parse_race_part_2;
+use aoc_common::input;
+use aoc_common::solution::{self, Solution};
+use day_06::Day06;
 
 fn main() {
-    let input = include_str!("../input.txt");
-    let race = parse_race_part_2(input);
-
-    println!("{}", race.winning_button_hold_times.len());
+    let input = input::load(Day06::INPUT);
+    let ways_to_win = solution::solve_or_exit::<Day06>(2, &input);
+    println!("{ways_to_win}");
 }
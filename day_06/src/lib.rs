@@ -1,101 +1,145 @@
+use aoc_common::error::ParseError;
+use aoc_common::parse;
+use aoc_common::solution::Solution;
 use regex::Regex;
 
-pub fn parse_races(race_descriptions: &str) -> Vec<Race> {
-    let time_regex = Regex::new(r"^Time: +(.+)$").expect("regex is valid");
-    let distance_regex = Regex::new(r"^Distance: +(.+)$").expect("regex is valid");
+pub struct Day06;
 
-    let mut race_times: Vec<u64> = vec![];
-    let mut race_durations: Vec<u64> = vec![];
+// Part 1 reads the sheet as several races, part 2 as one long race with the
+// spaces between digits ignored.
+pub struct RaceSheet {
+    pub races: Vec<Race>,
+    pub long_race: Race,
+}
 
-    for line in race_descriptions.lines() {
-        if let Some(captures) = time_regex.captures(line) {
-            let (_, [times]) = captures.extract();
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = include_str!("input.txt");
 
-            let mut times: Vec<u64> = times
-                .split_whitespace()
-                .map(|t| t.parse().unwrap())
-                .collect();
-            race_times.append(&mut times);
-        }
+    type Puzzle = RaceSheet;
+    type Answer = usize;
 
-        if let Some(captures) = distance_regex.captures(line) {
-            let (_, [durations]) = captures.extract();
-            let mut durations = durations
-                .split_whitespace()
-                .map(|t| t.parse().unwrap())
-                .collect();
-            race_durations.append(&mut durations);
-        }
+    fn parse(input: &str) -> Result<RaceSheet, ParseError> {
+        Ok(RaceSheet {
+            races: parse_races(input)?,
+            long_race: parse_race_part_2(input)?,
+        })
     }
 
-    race_times
-        .iter()
-        .enumerate()
-        .map(|(i, time)| Race::new(*time, race_durations[i]))
-        .collect()
+    fn part_1(race_sheet: &RaceSheet) -> Result<usize, ParseError> {
+        Ok(race_sheet
+            .races
+            .iter()
+            .map(|race| race.winning_button_hold_times().len())
+            .product())
+    }
+
+    fn part_2(race_sheet: &RaceSheet) -> Result<usize, ParseError> {
+        Ok(race_sheet.long_race.winning_button_hold_times().len())
+    }
 }
 
-pub fn parse_race_part_2(race_descriptions: &str) -> Race {
-    let time_regex = Regex::new(r"^Time: +(.+)$").expect("regex is valid");
-    let distance_regex = Regex::new(r"^Distance: +(.+)$").expect("regex is valid");
+pub fn parse_races(race_descriptions: &str) -> Result<Vec<Race>, ParseError> {
+    let (time_line, times) = find_line(race_descriptions, r"^Time: +(.+)$", "a \"Time:\" line")?;
+    let (distance_line, durations) = find_line(
+        race_descriptions,
+        r"^Distance: +(.+)$",
+        "a \"Distance:\" line",
+    )?;
+
+    let race_times: Vec<u64> =
+        parse::numbers(times.1).map_err(|err| err.within(times.0, times.1).on_line(time_line))?;
+    let race_durations: Vec<u64> = parse::numbers(durations.1)
+        .map_err(|err| err.within(durations.0, durations.1).on_line(distance_line))?;
+
+    if race_times.len() != race_durations.len() {
+        let expected = format!("a distance for each of the {} races", race_times.len());
+        return Err(
+            ParseError::expected(&expected, durations.0, durations.1).on_line(distance_line)
+        );
+    }
 
-    let mut race_time: u64 = 0;
-    let mut race_duration: u64 = 0;
+    Ok(race_times
+        .iter()
+        .zip(race_durations)
+        .map(|(time, duration)| Race::new(*time, duration))
+        .collect())
+}
 
-    for line in race_descriptions.lines() {
-        if let Some(captures) = time_regex.captures(line) {
-            let (_, [time]) = captures.extract();
-            let time: u64 = time.replace(" ", "").parse().unwrap();
-            race_time = time;
-        }
+pub fn parse_race_part_2(race_descriptions: &str) -> Result<Race, ParseError> {
+    let (time_line, time) = find_line(race_descriptions, r"^Time: +(.+)$", "a \"Time:\" line")?;
+    let (distance_line, duration) = find_line(
+        race_descriptions,
+        r"^Distance: +(.+)$",
+        "a \"Distance:\" line",
+    )?;
+
+    // The spaces are removed before parsing, so errors point at the start of the number
+    let race_time: u64 = parse::number(&time.1.replace(' ', ""))
+        .map_err(|err| err.within(time.0, time.1).on_line(time_line))?;
+    let race_duration: u64 = parse::number(&duration.1.replace(' ', ""))
+        .map_err(|err| err.within(duration.0, duration.1).on_line(distance_line))?;
+
+    Ok(Race::new(race_time, race_duration))
+}
 
-        if let Some(captures) = distance_regex.captures(line) {
-            let (_, [duration]) = captures.extract();
-            let duration: u64 = duration.replace(" ", "").parse().unwrap();
-            race_duration = duration;
+// Finds the line matching `pattern`, returning its line number, the line, and
+// the text captured by the pattern
+fn find_line<'a>(
+    race_descriptions: &'a str,
+    pattern: &str,
+    expected: &str,
+) -> Result<(usize, (&'a str, &'a str)), ParseError> {
+    let regex = Regex::new(pattern).expect("regex is valid");
+
+    for (i, line) in race_descriptions.lines().enumerate() {
+        if let Some(captures) = regex.captures(line) {
+            let (_, [numbers]) = captures.extract();
+            return Ok((i + 1, (line, numbers)));
         }
     }
 
-    Race::new(race_time, race_duration)
+    // Pointing just past the end of the input, since there's no line to show
+    let past_end = race_descriptions.lines().count() + 1;
+    Err(ParseError::expected(expected, "", "").on_line(past_end))
 }
 
 #[derive(PartialEq, Debug)]
 pub struct Race {
     duration: u64,
     record_distance: u64,
-    pub winning_button_hold_times: Vec<u64>,
 }
 
 impl Race {
     pub fn new(duration: u64, record_distance: u64) -> Race {
-        let mut race = Race {
+        Race {
             duration,
             record_distance,
-            winning_button_hold_times: vec![],
-        };
-
-        race.calculate_winning_button_hold_times();
-        race
+        }
     }
 
-    fn calculate_winning_button_hold_times(&mut self) {
-        for button_hold_time in 0..self.duration {
-            let distance = (self.duration - button_hold_time) * button_hold_time;
-            if distance > self.record_distance {
-                self.winning_button_hold_times.push(button_hold_time);
-            }
-        }
+    // Tries every hold time, which for part 2's long race takes a while, so
+    // it's only done by the part that needs it rather than when parsing
+    pub fn winning_button_hold_times(&self) -> Vec<u64> {
+        (0..self.duration)
+            .filter(|button_hold_time| {
+                let distance = (self.duration - button_hold_time) * button_hold_time;
+                distance > self.record_distance
+            })
+            .collect()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::diagnostic;
+    use aoc_common::error::ParseErrorKind;
 
     #[test]
     fn sample_input_1() {
         let race = Race::new(7, 9);
-        assert_eq!(vec![2, 3, 4, 5], race.winning_button_hold_times);
+        assert_eq!(vec![2, 3, 4, 5], race.winning_button_hold_times());
     }
 
     #[test]
@@ -103,7 +147,7 @@ mod tests {
         let race = Race::new(15, 40);
         assert_eq!(
             vec![4, 5, 6, 7, 8, 9, 10, 11],
-            race.winning_button_hold_times
+            race.winning_button_hold_times()
         );
     }
 
@@ -112,7 +156,36 @@ mod tests {
         let race = Race::new(30, 200);
         assert_eq!(
             vec![11, 12, 13, 14, 15, 16, 17, 18, 19],
-            race.winning_button_hold_times
+            race.winning_button_hold_times()
+        );
+    }
+
+    #[test]
+    fn missing_distance_line() {
+        let error = parse_races("Time:      7  15   30\n").unwrap_err();
+        assert_eq!(
+            "line 2, column 1: expected a \"Distance:\" line, found nothing",
+            error.to_string()
+        );
+        assert_eq!(
+            "error: expected a \"Distance:\" line, found nothing\n --> line 2, column 1\n",
+            diagnostic::render("Time:      7  15   30\n", &error)
         );
     }
+
+    #[test]
+    fn missing_distance() {
+        let error = parse_races("Time:      7  15   30\nDistance:  9  40\n").unwrap_err();
+        assert_eq!(2, error.line);
+        assert_eq!(12, error.column);
+    }
+
+    #[test]
+    fn invalid_long_race_time() {
+        let error =
+            parse_race_part_2("Time:      7  1x   30\nDistance:  9  40  200\n").unwrap_err();
+        assert_eq!(ParseErrorKind::InvalidNumber, error.kind);
+        assert_eq!(1, error.line);
+        assert_eq!(12, error.column);
+    }
 }
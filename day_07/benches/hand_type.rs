@@ -0,0 +1,29 @@
+use aoc_common::solution::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_07::{part_2, CamelCardsHandType, Day07};
+
+fn hand_type_from(c: &mut Criterion) {
+    let hands: Vec<&str> = Day07::INPUT
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .collect();
+
+    c.bench_function("CamelCardsHandType::from", |b| {
+        b.iter(|| {
+            for hand in &hands {
+                let _ = black_box(CamelCardsHandType::from(black_box(hand)));
+            }
+        })
+    });
+
+    c.bench_function("part_2::CamelCardsHandType::from", |b| {
+        b.iter(|| {
+            for hand in &hands {
+                let _ = black_box(part_2::CamelCardsHandType::from(black_box(hand)));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, hand_type_from);
+criterion_main!(benches);
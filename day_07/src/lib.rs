@@ -1,7 +1,115 @@
+use aoc_common::error::ParseError;
+use aoc_common::parse;
+use aoc_common::solution::Solution;
 use std::collections::HashMap;
 
 pub mod part_2;
 
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    // Every hand is read both ways up front, with jacks for part 1 and jokers
+    // for part 2, so bad cards are reported before either part runs.
+    type Puzzle = HandList;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<HandList, ParseError> {
+        let mut hand_list = HandList {
+            hands: vec![],
+            joker_hands: vec![],
+        };
+
+        for (i, line) in input.lines().enumerate() {
+            let (cards, bid) = parse_hand_description(line).map_err(|err| err.on_line(i + 1))?;
+            let in_line = |err: ParseError| err.within(line, cards).on_line(i + 1);
+
+            hand_list.hands.push(CamelCardsHand {
+                hand_type: CamelCardsHandType::from(cards).map_err(in_line)?,
+                bid,
+            });
+            hand_list.joker_hands.push(part_2::CamelCardsHand {
+                hand_type: part_2::CamelCardsHandType::from(cards).map_err(in_line)?,
+                bid,
+            });
+        }
+
+        Ok(hand_list)
+    }
+
+    fn part_1(hand_list: &HandList) -> Result<u32, ParseError> {
+        let mut hands: Vec<_> = hand_list.hands.iter().collect();
+
+        hands.sort_by(|a, b| a.hand_type.cmp(&b.hand_type));
+        Ok(total_winnings(hands.iter().map(|hand| hand.bid)))
+    }
+
+    fn part_2(hand_list: &HandList) -> Result<u32, ParseError> {
+        let mut hands: Vec<_> = hand_list.joker_hands.iter().collect();
+
+        hands.sort_by(|a, b| a.hand_type.cmp(&b.hand_type));
+        Ok(total_winnings(hands.iter().map(|hand| hand.bid)))
+    }
+}
+
+pub struct HandList {
+    pub hands: Vec<CamelCardsHand>,
+    pub joker_hands: Vec<part_2::CamelCardsHand>,
+}
+
+fn parse_hand_description(line: &str) -> Result<(&str, u32), ParseError> {
+    let Some((cards, bid)) = line.split_once(' ') else {
+        return Err(ParseError::expected(
+            "a hand and a bid, like \"32T3K 765\"",
+            line,
+            line,
+        ));
+    };
+    let bid = bid.trim();
+
+    Ok((
+        cards,
+        parse::number(bid).map_err(|err| err.within(line, bid))?,
+    ))
+}
+
+// Reads the five cards of a hand, using `card_from` to decide what each
+// character means
+pub(crate) fn parse_cards<C>(
+    hand_string: &str,
+    card_from: fn(char) -> Option<C>,
+) -> Result<[C; 5], ParseError> {
+    let mut cards = vec![];
+
+    for (i, c) in hand_string.char_indices() {
+        match card_from(c) {
+            Some(card) => cards.push(card),
+            None => {
+                let offending = &hand_string[i..i + c.len_utf8()];
+                return Err(ParseError::expected(
+                    "a card (2-9, T, J, Q, K or A)",
+                    hand_string,
+                    offending,
+                ));
+            }
+        }
+    }
+
+    cards
+        .try_into()
+        .map_err(|_| ParseError::expected("a hand of five cards", hand_string, hand_string))
+}
+
+// Bids must already be sorted from weakest to strongest hand
+fn total_winnings(sorted_bids: impl Iterator<Item = u32>) -> u32 {
+    sorted_bids
+        .enumerate()
+        .map(|(i, bid)| bid * (i + 1) as u32)
+        .sum()
+}
+
 #[derive(Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Clone)]
 pub enum Card {
     Two,
@@ -52,13 +160,8 @@ pub enum CamelCardsHandType {
 }
 
 impl CamelCardsHandType {
-    pub fn from(hand_string: &str) -> CamelCardsHandType {
-        let cards: [Card; 5] = hand_string
-            .chars()
-            .map(|c| Card::from(c).expect("Invalid card in hand string"))
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
+    pub fn from(hand_string: &str) -> Result<CamelCardsHandType, ParseError> {
+        let cards = parse_cards(hand_string, Card::from)?;
 
         let mut card_count: HashMap<&Card, u8> = HashMap::new();
         for card in &cards {
@@ -67,18 +170,18 @@ impl CamelCardsHandType {
         }
 
         if *(card_count.get(&cards[0]).unwrap()) == 5 {
-            return CamelCardsHandType::FiveOfAKind(cards);
+            return Ok(CamelCardsHandType::FiveOfAKind(cards));
         }
 
         if card_count.iter().any(|(_, count)| *count == 4) {
-            return CamelCardsHandType::FourOfAKind(cards);
+            return Ok(CamelCardsHandType::FourOfAKind(cards));
         }
 
         if card_count.iter().any(|(_, count)| *count == 3) {
             return if card_count.iter().any(|(_, count)| *count == 2) {
-                CamelCardsHandType::FullHouse(cards)
+                Ok(CamelCardsHandType::FullHouse(cards))
             } else {
-                CamelCardsHandType::ThreeOfAKind(cards)
+                Ok(CamelCardsHandType::ThreeOfAKind(cards))
             };
         }
 
@@ -89,15 +192,15 @@ impl CamelCardsHandType {
             .len()
             == 2
         {
-            return CamelCardsHandType::TwoPair(cards);
+            return Ok(CamelCardsHandType::TwoPair(cards));
         }
 
         if card_count.iter().any(|(_, count)| *count == 2) {
-            return CamelCardsHandType::OnePair(cards);
+            return Ok(CamelCardsHandType::OnePair(cards));
         }
 
         if card_count.iter().all(|(_, count)| *count == 1) {
-            return CamelCardsHandType::HighCard(cards);
+            return Ok(CamelCardsHandType::HighCard(cards));
         }
 
         panic!("Unexpected hand! {:?}", cards)
@@ -116,91 +219,122 @@ mod tests {
 
     #[test]
     fn test_five_of_a_kind() {
-        let hand_1 = CamelCardsHandType::from("AAAAA");
-        let hand_2 = CamelCardsHandType::from("22222");
+        let hand_1 = CamelCardsHandType::from("AAAAA").unwrap();
+        let hand_2 = CamelCardsHandType::from("22222").unwrap();
         assert!(matches!(hand_1, CamelCardsHandType::FiveOfAKind(_)));
         assert!(matches!(hand_2, CamelCardsHandType::FiveOfAKind(_)));
     }
 
     #[test]
     fn test_four_of_a_kind() {
-        let hand_1 = CamelCardsHandType::from("AAAAK");
-        let hand_2 = CamelCardsHandType::from("AKKKK");
+        let hand_1 = CamelCardsHandType::from("AAAAK").unwrap();
+        let hand_2 = CamelCardsHandType::from("AKKKK").unwrap();
         assert!(matches!(hand_1, CamelCardsHandType::FourOfAKind(_)));
         assert!(matches!(hand_2, CamelCardsHandType::FourOfAKind(_)));
     }
 
     #[test]
     fn test_full_house() {
-        let hand_1 = CamelCardsHandType::from("AAKKK");
-        let hand_2 = CamelCardsHandType::from("AAAKK");
+        let hand_1 = CamelCardsHandType::from("AAKKK").unwrap();
+        let hand_2 = CamelCardsHandType::from("AAAKK").unwrap();
         assert!(matches!(hand_1, CamelCardsHandType::FullHouse(_)));
         assert!(matches!(hand_2, CamelCardsHandType::FullHouse(_)));
     }
 
     #[test]
     fn test_three_of_a_kind() {
-        let hand_1 = CamelCardsHandType::from("T55J5");
-        let hand_2 = CamelCardsHandType::from("T55J5");
+        let hand_1 = CamelCardsHandType::from("T55J5").unwrap();
+        let hand_2 = CamelCardsHandType::from("T55J5").unwrap();
         assert!(matches!(hand_1, CamelCardsHandType::ThreeOfAKind(_)));
         assert!(matches!(hand_2, CamelCardsHandType::ThreeOfAKind(_)));
     }
 
     #[test]
     fn test_two_pair() {
-        let hand_1 = CamelCardsHandType::from("KK677");
-        let hand_2 = CamelCardsHandType::from("KTJJT");
+        let hand_1 = CamelCardsHandType::from("KK677").unwrap();
+        let hand_2 = CamelCardsHandType::from("KTJJT").unwrap();
         assert!(matches!(hand_1, CamelCardsHandType::TwoPair(_)));
         assert!(matches!(hand_2, CamelCardsHandType::TwoPair(_)));
     }
 
     #[test]
     fn test_one_pair() {
-        let hand = CamelCardsHandType::from("32T3K");
+        let hand = CamelCardsHandType::from("32T3K").unwrap();
         assert!(matches!(hand, CamelCardsHandType::OnePair(_)))
     }
 
     #[test]
     fn test_high_card() {
-        let hand = CamelCardsHandType::from("32T4K");
+        let hand = CamelCardsHandType::from("32T4K").unwrap();
         assert!(matches!(hand, CamelCardsHandType::HighCard(_)))
     }
 
     #[test]
     fn test_full_house_beats_three_of_a_kind() {
-        let hand_1 = CamelCardsHandType::from("AAKKK");
-        let hand_2 = CamelCardsHandType::from("T55J5");
+        let hand_1 = CamelCardsHandType::from("AAKKK").unwrap();
+        let hand_2 = CamelCardsHandType::from("T55J5").unwrap();
         assert!(hand_2 < hand_1);
     }
 
     #[test]
     fn test_two_full_house() {
-        let hand_1 = CamelCardsHandType::from("AAKKK");
-        let hand_2 = CamelCardsHandType::from("AAAKK");
+        let hand_1 = CamelCardsHandType::from("AAKKK").unwrap();
+        let hand_2 = CamelCardsHandType::from("AAAKK").unwrap();
 
         assert!(hand_2 > hand_1);
     }
 
     #[test]
     fn test_two_two_pair() {
-        let hand_1 = CamelCardsHandType::from("KTJJT");
-        let hand_2 = CamelCardsHandType::from("KK677");
+        let hand_1 = CamelCardsHandType::from("KTJJT").unwrap();
+        let hand_2 = CamelCardsHandType::from("KK677").unwrap();
 
         assert!(hand_2 > hand_1);
     }
 
     #[test]
     fn test_two_three_pair() {
-        let hand_1 = CamelCardsHandType::from("T55J5");
-        let hand_2 = CamelCardsHandType::from("QQQJA");
+        let hand_1 = CamelCardsHandType::from("T55J5").unwrap();
+        let hand_2 = CamelCardsHandType::from("QQQJA").unwrap();
 
         assert!(hand_2 > hand_1);
     }
 
     #[test]
     fn one_pair() {
-        let hand = CamelCardsHandType::from("8J382");
+        let hand = CamelCardsHandType::from("8J382").unwrap();
         println!("{:?}", hand);
         assert!(matches!(hand, CamelCardsHandType::OnePair(_)))
     }
+
+    #[test]
+    fn invalid_card() {
+        let error = CamelCardsHandType::from("32X3K").unwrap_err();
+        assert_eq!(3, error.column);
+        assert_eq!("X", error.text);
+    }
+
+    #[test]
+    fn too_few_cards() {
+        let error = CamelCardsHandType::from("32T3").unwrap_err();
+        assert_eq!(
+            "line 1, column 1: expected a hand of five cards, found \"32T3\"",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn invalid_card_in_hand_list() {
+        let error = Day07::parse("32T3K 765\nT55Z5 684\n").err().unwrap();
+        assert_eq!(2, error.line);
+        assert_eq!(4, error.column);
+    }
+
+    #[test]
+    fn invalid_bid() {
+        let error = Day07::parse("32T3K 76x5\n").err().unwrap();
+        assert_eq!(1, error.line);
+        assert_eq!(7, error.column);
+        assert_eq!("76x5", error.text);
+    }
 }
@@ -1,3 +1,4 @@
+use aoc_common::error::ParseError;
 use std::collections::HashMap;
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Clone)]
@@ -50,13 +51,8 @@ pub enum CamelCardsHandType {
 }
 
 impl CamelCardsHandType {
-    pub fn from(hand_string: &str) -> CamelCardsHandType {
-        let cards: [Card; 5] = hand_string
-            .chars()
-            .map(|c| Card::from(c).expect("Invalid card in hand string"))
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
+    pub fn from(hand_string: &str) -> Result<CamelCardsHandType, ParseError> {
+        let cards = crate::parse_cards(hand_string, Card::from)?;
 
         let mut card_count: HashMap<&Card, u8> = HashMap::new();
         for card in &cards {
@@ -67,14 +63,16 @@ impl CamelCardsHandType {
         let joker_count = card_count.get(&Card::Joker).unwrap_or(&0);
 
         if *(card_count.get(&cards[0]).unwrap()) == 5 {
-            return CamelCardsHandType::FiveOfAKind(cards);
+            return Ok(CamelCardsHandType::FiveOfAKind(cards));
         }
 
         if card_count.iter().any(|(_, count)| *count == 4) {
             return match joker_count {
-                1 | 4 => CamelCardsHandType::FiveOfAKind(cards),
-                0 => CamelCardsHandType::FourOfAKind(cards),
-                _ => panic!("Impossible number of jokers ({joker_count}) in un-upgraded four of a kind"),
+                1 | 4 => Ok(CamelCardsHandType::FiveOfAKind(cards)),
+                0 => Ok(CamelCardsHandType::FourOfAKind(cards)),
+                _ => panic!(
+                    "Impossible number of jokers ({joker_count}) in un-upgraded four of a kind"
+                ),
             };
         }
 
@@ -82,17 +80,21 @@ impl CamelCardsHandType {
             && card_count.iter().any(|(_, count)| *count == 2)
         {
             return match joker_count {
-                3 | 2 => CamelCardsHandType::FiveOfAKind(cards),
-                0 => CamelCardsHandType::FullHouse(cards),
-                _ => panic!("Impossible number of jokers ({joker_count}) in un-upgraded full house!"),
+                3 | 2 => Ok(CamelCardsHandType::FiveOfAKind(cards)),
+                0 => Ok(CamelCardsHandType::FullHouse(cards)),
+                _ => {
+                    panic!("Impossible number of jokers ({joker_count}) in un-upgraded full house!")
+                }
             };
         }
 
         if card_count.iter().any(|(_, count)| *count == 3) {
             return match joker_count {
-                1 | 3 => CamelCardsHandType::FourOfAKind(cards),
-                0 => CamelCardsHandType::ThreeOfAKind(cards),
-                _ => panic!("Impossible number of jokers ({joker_count}) in un-upgraded three of a kind!"),
+                1 | 3 => Ok(CamelCardsHandType::FourOfAKind(cards)),
+                0 => Ok(CamelCardsHandType::ThreeOfAKind(cards)),
+                _ => panic!(
+                    "Impossible number of jokers ({joker_count}) in un-upgraded three of a kind!"
+                ),
             };
         }
 
@@ -104,26 +106,28 @@ impl CamelCardsHandType {
             == 2
         {
             return match joker_count {
-                2 => CamelCardsHandType::FourOfAKind(cards),
-                1 => CamelCardsHandType::FullHouse(cards),
-                0 => CamelCardsHandType::TwoPair(cards),
+                2 => Ok(CamelCardsHandType::FourOfAKind(cards)),
+                1 => Ok(CamelCardsHandType::FullHouse(cards)),
+                0 => Ok(CamelCardsHandType::TwoPair(cards)),
                 _ => panic!("Impossible number of jokers ({joker_count}) in un-upgraded two pair!"),
             };
         }
 
         if card_count.iter().any(|(_, count)| *count == 2) {
             return match joker_count {
-                2 | 1 => CamelCardsHandType::ThreeOfAKind(cards),
-                0 => CamelCardsHandType::OnePair(cards),
+                2 | 1 => Ok(CamelCardsHandType::ThreeOfAKind(cards)),
+                0 => Ok(CamelCardsHandType::OnePair(cards)),
                 _ => panic!("Impossible number of jokers ({joker_count}) in un-upgraded one pair!"),
             };
         }
 
         if card_count.iter().all(|(_, count)| *count == 1) {
             return match joker_count {
-                1 => CamelCardsHandType::OnePair(cards),
-                0 => CamelCardsHandType::HighCard(cards),
-                _ => panic!("Impossible number of jokers ({joker_count}) in un-upgraded high card!"),
+                1 => Ok(CamelCardsHandType::OnePair(cards)),
+                0 => Ok(CamelCardsHandType::HighCard(cards)),
+                _ => {
+                    panic!("Impossible number of jokers ({joker_count}) in un-upgraded high card!")
+                }
             };
         }
 
@@ -143,27 +147,27 @@ mod tests {
 
     #[test]
     fn test_five_of_a_kind() {
-        let hand_1 = CamelCardsHandType::from("AAAAA");
-        let hand_2 = CamelCardsHandType::from("22222");
+        let hand_1 = CamelCardsHandType::from("AAAAA").unwrap();
+        let hand_2 = CamelCardsHandType::from("22222").unwrap();
         assert!(matches!(hand_1, CamelCardsHandType::FiveOfAKind(_)));
         assert!(matches!(hand_2, CamelCardsHandType::FiveOfAKind(_)));
     }
 
     #[test]
     fn test_four_of_a_kind() {
-        let hand_1 = CamelCardsHandType::from("AAAAK");
-        let hand_2 = CamelCardsHandType::from("AKKKK");
+        let hand_1 = CamelCardsHandType::from("AAAAK").unwrap();
+        let hand_2 = CamelCardsHandType::from("AKKKK").unwrap();
         assert!(matches!(hand_1, CamelCardsHandType::FourOfAKind(_)));
         assert!(matches!(hand_2, CamelCardsHandType::FourOfAKind(_)));
     }
 
     #[test]
     fn test_full_house() {
-        let hand_1 = CamelCardsHandType::from("AAKKK");
-        let hand_2 = CamelCardsHandType::from("AAAKK");
-        let hand_3 = CamelCardsHandType::from("JAAKK");
-        let hand_4 = CamelCardsHandType::from("JJAAA");
-        let hand_5 = CamelCardsHandType::from("JJJKK");
+        let hand_1 = CamelCardsHandType::from("AAKKK").unwrap();
+        let hand_2 = CamelCardsHandType::from("AAAKK").unwrap();
+        let hand_3 = CamelCardsHandType::from("JAAKK").unwrap();
+        let hand_4 = CamelCardsHandType::from("JJAAA").unwrap();
+        let hand_5 = CamelCardsHandType::from("JJJKK").unwrap();
         assert!(matches!(hand_1, CamelCardsHandType::FullHouse(_)));
         assert!(matches!(hand_2, CamelCardsHandType::FullHouse(_)));
         assert!(matches!(hand_3, CamelCardsHandType::FullHouse(_)));
@@ -173,68 +177,68 @@ mod tests {
 
     #[test]
     fn test_three_of_a_kind() {
-        let hand_1 = CamelCardsHandType::from("T55J5");
+        let hand_1 = CamelCardsHandType::from("T55J5").unwrap();
         assert!(matches!(hand_1, CamelCardsHandType::FourOfAKind(_)));
     }
 
     #[test]
     fn test_two_pair() {
-        let hand_1 = CamelCardsHandType::from("KK677");
-        let hand_2 = CamelCardsHandType::from("KTJJT");
+        let hand_1 = CamelCardsHandType::from("KK677").unwrap();
+        let hand_2 = CamelCardsHandType::from("KTJJT").unwrap();
         assert!(matches!(hand_1, CamelCardsHandType::TwoPair(_)));
         assert!(matches!(hand_2, CamelCardsHandType::FourOfAKind(_)));
     }
 
     #[test]
     fn test_one_pair() {
-        let hand = CamelCardsHandType::from("32T3K");
+        let hand = CamelCardsHandType::from("32T3K").unwrap();
         assert!(matches!(hand, CamelCardsHandType::OnePair(_)))
     }
 
     #[test]
     fn test_high_card() {
-        let hand = CamelCardsHandType::from("32T4K");
+        let hand = CamelCardsHandType::from("32T4K").unwrap();
         assert!(matches!(hand, CamelCardsHandType::HighCard(_)))
     }
 
     #[test]
     fn test_full_house_beats_three_of_a_kind() {
-        let hand_1 = CamelCardsHandType::from("AAKKK");
-        let hand_2 = CamelCardsHandType::from("T55J5");
+        let hand_1 = CamelCardsHandType::from("AAKKK").unwrap();
+        let hand_2 = CamelCardsHandType::from("T55J5").unwrap();
         assert!(hand_2 > hand_1);
     }
 
     #[test]
     fn test_two_full_house() {
-        let hand_1 = CamelCardsHandType::from("AAKKK");
-        let hand_2 = CamelCardsHandType::from("AAAKK");
+        let hand_1 = CamelCardsHandType::from("AAKKK").unwrap();
+        let hand_2 = CamelCardsHandType::from("AAAKK").unwrap();
 
         assert!(hand_2 > hand_1);
     }
 
     #[test]
     fn test_two_three_pair() {
-        let hand_1 = CamelCardsHandType::from("T55J5");
-        let hand_2 = CamelCardsHandType::from("QQQJA");
+        let hand_1 = CamelCardsHandType::from("T55J5").unwrap();
+        let hand_2 = CamelCardsHandType::from("QQQJA").unwrap();
 
         assert!(hand_2 > hand_1);
     }
 
     #[test]
     fn one_pair() {
-        let hand = CamelCardsHandType::from("8J382");
+        let hand = CamelCardsHandType::from("8J382").unwrap();
         assert!(matches!(hand, CamelCardsHandType::ThreeOfAKind(_)))
     }
 
     #[test]
     fn one_pair_sample_2() {
-        let hand = CamelCardsHandType::from("32T3K");
+        let hand = CamelCardsHandType::from("32T3K").unwrap();
         assert!(matches!(hand, CamelCardsHandType::OnePair(_)))
     }
 
     #[test]
     fn two_pair_sample_2() {
-        let hand = CamelCardsHandType::from("KK677");
+        let hand = CamelCardsHandType::from("KK677").unwrap();
         assert!(matches!(hand, CamelCardsHandType::TwoPair(_)))
     }
 }
@@ -1,14 +1,298 @@
-pub fn add(left: usize, right: usize) -> usize {
-    left + right
+use aoc_common::error::ParseError;
+use aoc_common::parse;
+use aoc_common::solution::Solution;
+use num::integer;
+use regex::Regex;
+use std::collections::HashMap;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Puzzle = Network;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Network, ParseError> {
+        Network::from(input)
+    }
+
+    fn part_1(network: &Network) -> Result<u64, ParseError> {
+        for node in ["AAA", "ZZZ"] {
+            network.find(node)?;
+        }
+
+        network.route("AAA", "ZZZ", |node| node == "ZZZ")
+    }
+
+    // Every ghost reaches the end of its route at regular intervals, so all of
+    // them are on one at the lowest common multiple of the route lengths
+    fn part_2(network: &Network) -> Result<u64, ParseError> {
+        let mut starting_nodes: Vec<&String> = network
+            .nodes
+            .keys()
+            .filter(|node| node.ends_with('A'))
+            .collect();
+        // In the order they're defined, so the first bad one is reported
+        starting_nodes.sort_by_key(|node| network.node_lines[*node]);
+
+        let route_lengths = starting_nodes
+            .into_iter()
+            .map(|node| network.route(node, "a node ending in Z", |node| node.ends_with('Z')))
+            .collect::<Result<Vec<u64>, ParseError>>()?;
+
+        route_lengths
+            .into_iter()
+            .reduce(integer::lcm)
+            .ok_or_else(|| {
+                ParseError::expected("a node ending in A", "", "").on_line(network.line_count + 1)
+            })
+    }
+}
+
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub fn from(c: char) -> Option<Direction> {
+        match c {
+            'L' => Some(Direction::Left),
+            'R' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+}
+
+pub struct Network {
+    pub directions: Vec<Direction>,
+    pub nodes: HashMap<String, (String, String)>,
+    // Where each node is defined, so a route that goes nowhere can be pointed out
+    node_lines: HashMap<String, usize>,
+    line_count: usize,
+}
+
+impl Network {
+    pub fn from(network_description: &str) -> Result<Network, ParseError> {
+        let node_regex = Regex::new(r"^(\w{3}) = \((\w{3}), (\w{3})\)$").expect("valid regex");
+
+        let mut lines = network_description.lines().enumerate();
+        let directions_line = lines.next().map(|(_, line)| line).unwrap_or("");
+        let directions = parse_directions(directions_line)?;
+
+        match lines.next() {
+            Some((_, "")) => {}
+            Some((i, line)) => {
+                return Err(ParseError::expected("a blank line", line, line).on_line(i + 1))
+            }
+            None => return Err(ParseError::expected("a blank line", "", "").on_line(2)),
+        }
+
+        let mut node_descriptions = vec![];
+        for (i, line) in lines {
+            let [node, left, right] =
+                parse::captures(&node_regex, line).map_err(|err| err.on_line(i + 1))?;
+            node_descriptions.push((i, line, node, left, right));
+        }
+
+        let nodes: HashMap<String, (String, String)> = node_descriptions
+            .iter()
+            .map(|(_, _, node, left, right)| {
+                (node.to_string(), (left.to_string(), right.to_string()))
+            })
+            .collect();
+
+        // Every node we can be sent to has to exist, or following the directions would get stuck
+        for (i, line, _, left, right) in &node_descriptions {
+            for next_node in [left, right] {
+                if !nodes.contains_key(*next_node) {
+                    return Err(ParseError::expected(
+                        "a node defined in the network",
+                        line,
+                        next_node,
+                    )
+                    .on_line(i + 1));
+                }
+            }
+        }
+
+        Ok(Network {
+            directions,
+            nodes,
+            node_lines: node_descriptions
+                .iter()
+                .map(|(i, _, node, _, _)| (node.to_string(), i + 1))
+                .collect(),
+            line_count: network_description.lines().count(),
+        })
+    }
+
+    // The line `node` is defined on, or an error past the end of the network
+    // if it isn't
+    fn find(&self, node: &str) -> Result<usize, ParseError> {
+        self.node_lines.get(node).copied().ok_or_else(|| {
+            ParseError::expected(&format!("a node named {node}"), "", "")
+                .on_line(self.line_count + 1)
+        })
+    }
+
+    // Like `steps_from`, but a start that never reaches its destination is an
+    // error pointing at where the start is defined
+    fn route(
+        &self,
+        start: &str,
+        destination: &str,
+        is_destination: impl Fn(&str) -> bool,
+    ) -> Result<u64, ParseError> {
+        let line = self.find(start)?;
+
+        self.steps_from(start, is_destination).ok_or_else(|| {
+            let expected = format!("a node whose directions lead to {destination}");
+            ParseError::expected(&expected, start, start).on_line(line)
+        })
+    }
+
+    // Follows the directions (repeating them as often as needed) until a node
+    // satisfying `is_destination` is reached, returning the number of steps
+    // taken. None if it never will be, because the walk has come back to a
+    // node it already left from at the same point in the directions.
+    pub fn steps_from(
+        &self,
+        starting_position: &str,
+        is_destination: impl Fn(&str) -> bool,
+    ) -> Option<u64> {
+        // There are only this many (node, direction) pairs, so after this many
+        // steps one of them must have repeated
+        let limit = self.nodes.len() * self.directions.len();
+        let mut current_position = starting_position;
+
+        for (count, direction) in self.directions.iter().cycle().take(limit).enumerate() {
+            let (left, right) = self.nodes.get(current_position)?;
+            current_position = match direction {
+                Direction::Left => left,
+                Direction::Right => right,
+            };
+            if is_destination(current_position) {
+                return Some(count as u64 + 1);
+            }
+        }
+
+        None
+    }
+}
+
+fn parse_directions(directions_line: &str) -> Result<Vec<Direction>, ParseError> {
+    if directions_line.is_empty() {
+        return Err(ParseError::expected("a line of directions", "", ""));
+    }
+
+    directions_line
+        .char_indices()
+        .map(|(i, c)| {
+            Direction::from(c).ok_or_else(|| {
+                let offending = &directions_line[i..i + c.len_utf8()];
+                ParseError::expected("a direction (L or R)", directions_line, offending)
+            })
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::error::ParseErrorKind;
+
+    #[test]
+    fn invalid_direction() {
+        let error = Network::from("LRX\n\nAAA = (AAA, AAA)\n").err().unwrap();
+        assert_eq!(
+            "line 1, column 3: expected a direction (L or R), found \"X\"",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn missing_blank_line() {
+        let error = Network::from("LR\nAAA = (AAA, AAA)\n").err().unwrap();
+        assert_eq!(2, error.line);
+    }
+
+    #[test]
+    fn malformed_node() {
+        let error = Network::from("LR\n\nAAA = (AAA, AAA)\nBBB = AAA\n")
+            .err()
+            .unwrap();
+        assert_eq!(4, error.line);
+        assert_eq!("BBB = AAA", error.text);
+    }
+
+    #[test]
+    fn undefined_node() {
+        let error = Network::from("LR\n\nAAA = (AAA, ZZZ)\n").err().unwrap();
+        assert_eq!(3, error.line);
+        assert_eq!(13, error.column);
+        assert_eq!("ZZZ", error.text);
+    }
+
+    #[test]
+    fn missing_start_or_end() {
+        let network = Network::from("LR\n\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        let error = Day08::part_1(&network).unwrap_err();
+        assert_eq!(
+            ParseErrorKind::Expected("a node named AAA".to_string()),
+            error.kind
+        );
+        assert_eq!(4, error.line);
+
+        let network = Network::from("LR\n\nAAA = (AAA, AAA)\n").unwrap();
+        let error = Day08::part_1(&network).unwrap_err();
+        assert_eq!(
+            ParseErrorKind::Expected("a node named ZZZ".to_string()),
+            error.kind
+        );
+    }
+
+    #[test]
+    fn zzz_unreachable() {
+        let error = Day08::solve(
+            1,
+            "LR\n\nAAA = (BBB, AAA)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n",
+        )
+        .unwrap_err();
+        assert_eq!(
+            "line 3, column 1: expected a node whose directions lead to ZZZ, found \"AAA\"",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn ghost_unreachable() {
+        let input = "L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n11A = (11B, 11B)\n11B = (11A, ZZZ)\n";
+        assert_eq!(Ok(1), Day08::solve(1, input));
+
+        let error = Day08::solve(2, input).unwrap_err();
+        assert_eq!(5, error.line);
+        assert_eq!("11A", error.text);
+    }
+
+    #[test]
+    fn no_ghosts() {
+        let error = Day08::solve(2, "L\n\nZZZ = (ZZZ, ZZZ)\n").unwrap_err();
+        assert_eq!(
+            "line 4, column 1: expected a node ending in A, found nothing",
+            error.to_string()
+        );
+    }
 
     #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
+    fn reached_only_on_a_later_pass() {
+        // ZZZ is only taken from BBB on an R, which BBB first sees on the
+        // second time through the directions
+        let network =
+            Network::from("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        assert_eq!(Some(6), network.steps_from("AAA", |node| node == "ZZZ"));
     }
 }
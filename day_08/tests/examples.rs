@@ -0,0 +1,23 @@
+use aoc_common::solution::Solution;
+use day_08::Day08;
+
+// Part 1 has two examples, and part 2 its own since the part 1 examples only
+// have a single starting node
+const SAMPLE_INPUT: &str = include_str!("../src/sample_input.txt");
+const SAMPLE_INPUT_2: &str = include_str!("../src/sample_input_2.txt");
+const SAMPLE_INPUT_3: &str = include_str!("../src/sample_input_3.txt");
+
+#[test]
+fn part_1_example() {
+    assert_eq!(2, Day08::solve(1, SAMPLE_INPUT).unwrap());
+}
+
+#[test]
+fn part_1_example_with_repeated_directions() {
+    assert_eq!(6, Day08::solve(1, SAMPLE_INPUT_3).unwrap());
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(6, Day08::solve(2, SAMPLE_INPUT_2).unwrap());
+}
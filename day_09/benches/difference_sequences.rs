@@ -0,0 +1,22 @@
+use aoc_common::solution::Solution;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use day_09::{difference_sequences, Day09};
+
+fn bench_difference_sequences(c: &mut Criterion) {
+    let sequences = Day09::parse(Day09::INPUT).expect("bundled input parses");
+
+    c.bench_function("difference_sequences", |b| {
+        b.iter_batched(
+            || sequences.clone(),
+            |sequences| {
+                for sequence in sequences {
+                    black_box(difference_sequences(sequence));
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, bench_difference_sequences);
+criterion_main!(benches);
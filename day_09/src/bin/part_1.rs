@@ -1,17 +1,10 @@
-use day_09::{difference_sequences, extrapolate};
+use aoc_common::input;
+use aoc_common::solution::{self, Solution};
+use day_09::Day09;
 
 fn main() {
-    let input = include_str!("../input.txt");
-
-    let extrapolations_sum: i32 = input
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|number| number.parse::<i32>().unwrap())
-                .collect::<Vec<_>>()
-        })
-        .map(|sequence| extrapolate(difference_sequences(sequence)))
-        .sum();
+    let input = input::load(Day09::INPUT);
+    let extrapolations_sum = solution::solve_or_exit::<Day09>(1, &input);
 
     println!("{:?}", extrapolations_sum)
 }
@@ -1,21 +1,69 @@
+use aoc_common::error::ParseError;
+use aoc_common::parse;
+use aoc_common::solution::Solution;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Puzzle = Vec<Vec<i32>>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| parse_sequence(line).map_err(|err| err.on_line(i + 1)))
+            .collect()
+    }
+
+    fn part_1(sequences: &Vec<Vec<i32>>) -> Result<i32, ParseError> {
+        Ok(sequences
+            .iter()
+            .map(|sequence| extrapolate(difference_sequences(sequence.clone())))
+            .sum())
+    }
+
+    fn part_2(sequences: &Vec<Vec<i32>>) -> Result<i32, ParseError> {
+        Ok(sequences
+            .iter()
+            .map(|sequence| extrapolate_backwards(difference_sequences(sequence.clone())))
+            .sum())
+    }
+}
+
+fn parse_sequence(line: &str) -> Result<Vec<i32>, ParseError> {
+    let sequence = parse::numbers(line)?;
+
+    if sequence.is_empty() {
+        return Err(ParseError::expected("a sequence of numbers", line, line));
+    }
+
+    Ok(sequence)
+}
+
+// A sequence of a single number has an empty row of differences, which counts
+// as zeros
 pub fn extrapolate(sequences: Vec<Vec<i32>>) -> i32 {
     let mut previous_added_value = 0;
 
     for sequence in sequences {
-        previous_added_value = sequence.last().unwrap() + previous_added_value;
+        previous_added_value += sequence.last().unwrap_or(&0);
     }
 
-    return previous_added_value;
+    previous_added_value
 }
 
 pub fn extrapolate_backwards(sequences: Vec<Vec<i32>>) -> i32 {
     let mut previous_added_value = 0;
 
     for sequence in sequences {
-        previous_added_value = sequence.first().unwrap() - previous_added_value;
+        previous_added_value = sequence.first().unwrap_or(&0) - previous_added_value;
     }
 
-    return previous_added_value;
+    previous_added_value
 }
 
 pub fn difference_sequences(sequence: Vec<i32>) -> Vec<Vec<i32>> {
@@ -25,13 +73,13 @@ pub fn difference_sequences(sequence: Vec<i32>) -> Vec<Vec<i32>> {
         result.push(sequence[i] - sequence[i - 1]);
     }
 
-    return if result.iter().all(|x| *x == 0) {
+    if result.iter().all(|x| *x == 0) {
         vec![result, sequence]
     } else {
         let mut differences = difference_sequences(result);
         differences.push(sequence);
         differences
-    };
+    }
 }
 
 #[cfg(test)]
@@ -83,4 +131,26 @@ mod tests {
 
         assert_eq!(18, extrapolate(sequences));
     }
+
+    #[test]
+    fn single_number() {
+        let sequences = Day09::parse("5\n").unwrap();
+        assert_eq!(5, Day09::part_1(&sequences).unwrap());
+        assert_eq!(5, Day09::part_2(&sequences).unwrap());
+    }
+
+    #[test]
+    fn sequence_with_invalid_number() {
+        let error = Day09::parse("0 3 6 9 12 15\n1 3 6 1O 15 21\n").unwrap_err();
+        assert_eq!(
+            "line 2, column 7: \"1O\" is not a valid number",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn empty_sequence() {
+        let error = Day09::parse("0 3 6 9 12 15\n\n").unwrap_err();
+        assert_eq!(2, error.line);
+    }
 }
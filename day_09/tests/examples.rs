@@ -0,0 +1,14 @@
+use aoc_common::solution::Solution;
+use day_09::Day09;
+
+const SAMPLE_INPUT: &str = include_str!("../src/sample_input.txt");
+
+#[test]
+fn part_1_example() {
+    assert_eq!(114, Day09::solve(1, SAMPLE_INPUT).unwrap());
+}
+
+#[test]
+fn part_2_example() {
+    assert_eq!(2, Day09::solve(2, SAMPLE_INPUT).unwrap());
+}